@@ -0,0 +1,379 @@
+//! Build script support of the year crates.
+//!
+//! The build script of a year finds its `dayN.rs` files and the puzzles they declare with
+//! `create_solution!`, `create_alt_solution!` or a hand-written `impl Puzzle`, and generates the
+//! `mod` declarations of the days with a `days()` function which registers every puzzle, so none
+//! can be forgotten, and the `year()` of the crate. A puzzle type which can't be registered fails
+//! the build.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+/// The name of the generated file in `OUT_DIR`, include it in the `lib.rs` of the year.
+pub const DAYS_FILE: &str = "days.rs";
+
+/// The traits whose hand-written implementations declare a puzzle type.
+const PUZZLE_TRAITS: [&str; 3] = ["Puzzle", "SolutionPart1", "SolutionPart2"];
+
+/// A `dayN.rs` file of a year.
+#[derive(Debug)]
+struct DaySource {
+    day: u8,
+    path: PathBuf,
+    /// The puzzle types declared in the file, `PuzzleSolution` before its alternatives.
+    puzzles: Vec<String>,
+}
+
+/// Generate the days of the year crate being built, call this from its `build.rs`.
+///
+/// # Panics
+/// This function panics if the sources can't be read, a day declares a puzzle type which can't
+/// be registered or the generated file can't be written, which fails the build.
+pub fn register_days() {
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo");
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo");
+    let src = Path::new(&manifest_dir).join("src");
+
+    // Cargo checks a directory for any changed file, so new days are picked up as well
+    println!("cargo:rerun-if-changed={}", src.display());
+
+    let days = find_days(&src).unwrap_or_else(|err| panic!("Failed to read the days in {}: {}", src.display(), err));
+    let path = Path::new(&out_dir).join(DAYS_FILE);
+    fs::write(&path, generate(&days)).unwrap_or_else(|err| panic!("Failed to write {}: {}", path.display(), err));
+}
+
+fn find_days(src: &Path) -> io::Result<Vec<DaySource>> {
+    let mut days = Vec::new();
+    for entry in fs::read_dir(src)? {
+        let path = entry?.path();
+        let day = path.file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("day")?.strip_suffix(".rs")?.parse::<u8>().ok());
+        let Some(day) = day else {
+            continue;
+        };
+
+        let puzzles = find_puzzles(&fs::read_to_string(&path)?)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), err)))?;
+        days.push(DaySource { day, path, puzzles });
+    }
+
+    days.sort_by_key(|day| day.day);
+    Ok(days)
+}
+
+/// The puzzle types declared in the source of a day, `PuzzleSolution` first.
+///
+/// These are the types of the solution macros and of hand-written implementations of the puzzle
+/// traits. They have to be declared at the top level of the file, as `days()` can't reach into
+/// nested modules or blocks.
+fn find_puzzles(source: &str) -> Result<Vec<String>, String> {
+    let tokens = tokenize(source);
+    let mut puzzles = Vec::new();
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        let word = match token {
+            Token::Ident(word) => word,
+            Token::Punct('{') => {
+                depth += 1;
+                continue;
+            }
+            Token::Punct('}') => {
+                depth -= 1;
+                continue;
+            }
+            _ => continue,
+        };
+
+        let puzzle = match word.as_str() {
+            "create_solution" if tokens.get(i + 1) == Some(&Token::Punct('!')) => "PuzzleSolution".to_string(),
+            "create_alt_solution" if tokens.get(i + 1) == Some(&Token::Punct('!')) => {
+                match macro_args(&tokens[i + 2..]).get(1) {
+                    Some([Token::Ident(name)]) => name.clone(),
+                    _ => return Err("Found no puzzle name in a `create_alt_solution!`".to_string()),
+                }
+            }
+            "for" if matches!(&tokens[..i], [.., Token::Ident(name)] if PUZZLE_TRAITS.contains(&name.as_str())) => {
+                implemented_type(&tokens[i + 1..])?
+            }
+            _ => continue,
+        };
+
+        if depth > 0 {
+            return Err(format!("The puzzle {} is declared inside a module or block, declare it at the top level of the day", puzzle));
+        }
+        if !puzzles.contains(&puzzle) {
+            puzzles.push(puzzle);
+        }
+    }
+
+    puzzles.sort_by_key(|puzzle| puzzle != "PuzzleSolution");
+    Ok(puzzles)
+}
+
+/// The type after the `for` of a trait implementation, the last segment of its path.
+fn implemented_type(tokens: &[Token]) -> Result<String, String> {
+    let mut name = None;
+    for token in tokens {
+        match token {
+            Token::Ident(word) if word == "where" => break,
+            Token::Ident(segment) => name = Some(segment.clone()),
+            Token::Punct(':') => {}
+            Token::Punct('{') => break,
+            Token::Punct('<') => return Err(format!("Can't register the generic puzzle type {}", name.unwrap_or_default())),
+            _ => return Err("Can't register a puzzle type declared by a macro of the day".to_string()),
+        }
+    }
+
+    name.ok_or_else(|| "Found no type of a puzzle implementation".to_string())
+}
+
+/// The comma separated arguments of the macro call starting with its opening delimiter.
+fn macro_args(tokens: &[Token]) -> Vec<&[Token]> {
+    let mut args = Vec::new();
+    let mut depth = 0;
+    let mut start = 1;
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Punct('(' | '[' | '{') => depth += 1,
+            Token::Punct(')' | ']' | '}') => {
+                depth -= 1;
+                if depth == 0 {
+                    args.push(&tokens[start..i]);
+                    break;
+                }
+            }
+            Token::Punct(',') if depth == 1 => {
+                args.push(&tokens[start..i]);
+                start = i + 1;
+            }
+            _ if depth == 0 => break,
+            _ => {}
+        }
+    }
+
+    args
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Punct(char),
+    /// A string, character or number literal, its value doesn't matter here.
+    Literal,
+}
+
+/// Split the source into tokens, without comments and whitespace.
+///
+/// Literals are skipped as a whole, so macro calls in the example inputs aren't found.
+fn tokenize(source: &str) -> Vec<Token> {
+    let chars = source.chars().collect::<Vec<_>>();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && next == Some('/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            i = skip_block_comment(&chars, i);
+        } else if c == '"' {
+            i = skip_string(&chars, i + 1);
+            tokens.push(Token::Literal);
+        } else if c == '\'' {
+            i = skip_char_or_lifetime(&chars, i, &mut tokens);
+        } else if c.is_alphanumeric() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let word = chars[start..i].iter().collect::<String>();
+            let next = chars.get(i).copied();
+
+            if matches!(word.as_str(), "r" | "br" | "cr") && matches!(next, Some('"' | '#')) {
+                i = skip_raw_string(&chars, i);
+                tokens.push(Token::Literal);
+            } else if matches!(word.as_str(), "b" | "c") && next == Some('"') {
+                i = skip_string(&chars, i + 1);
+                tokens.push(Token::Literal);
+            } else if word == "b" && next == Some('\'') {
+                i = skip_char_or_lifetime(&chars, i, &mut tokens);
+            } else if word.starts_with(|c: char| c.is_ascii_digit()) {
+                tokens.push(Token::Literal);
+            } else {
+                tokens.push(Token::Ident(word));
+            }
+        } else {
+            tokens.push(Token::Punct(c));
+            i += 1;
+        }
+    }
+
+    tokens
+}
+
+/// The index after the nested block comment starting at `i`.
+fn skip_block_comment(chars: &[char], mut i: usize) -> usize {
+    let mut depth = 0;
+    while i < chars.len() {
+        match (chars[i], chars.get(i + 1)) {
+            ('/', Some('*')) => {
+                depth += 1;
+                i += 2;
+            }
+            ('*', Some('/')) => {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    break;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+
+    i
+}
+
+/// The index after the closing quote of the string whose content starts at `i`.
+fn skip_string(chars: &[char], mut i: usize) -> usize {
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            '"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+
+    i
+}
+
+/// The index after the raw string whose hashes or opening quote start at `i`.
+fn skip_raw_string(chars: &[char], mut i: usize) -> usize {
+    let hashes = chars[i..].iter().take_while(|&&c| c == '#').count();
+    i += hashes + 1;
+    while i < chars.len() {
+        if chars[i] == '"' && chars[i + 1..].iter().take(hashes).filter(|&&c| c == '#').count() == hashes {
+            return i + 1 + hashes;
+        }
+        i += 1;
+    }
+
+    i
+}
+
+/// The index after the character literal at `i`, a lifetime only adds its quote to the tokens.
+fn skip_char_or_lifetime(chars: &[char], i: usize, tokens: &mut Vec<Token>) -> usize {
+    match (chars.get(i + 1), chars.get(i + 2)) {
+        (Some('\\'), _) => {
+            let end = chars.get(i + 3..).unwrap_or_default().iter().position(|&c| c == '\'').map_or(chars.len(), |end| end + i + 4);
+            tokens.push(Token::Literal);
+            end
+        }
+        (Some(_), Some('\'')) => {
+            tokens.push(Token::Literal);
+            i + 3
+        }
+        _ => {
+            tokens.push(Token::Punct('\''));
+            i + 1
+        }
+    }
+}
+
+fn generate(days: &[DaySource]) -> String {
+    let mut code = String::from("// Generated by `aoc_build::register_days`, changes are overwritten.\n\n");
+    for day in days {
+        writeln!(code, "#[path = {:?}]", day.path.display().to_string()).unwrap();
+        writeln!(code, "mod day{};", day.day).unwrap();
+    }
+
+    code += "\n/// Every puzzle declared in the days of the year.\n";
+    code += "fn days() -> impl Iterator<Item = ::aoc_lib::day::Day> {\n    [\n";
+    for day in days {
+        for puzzle in &day.puzzles {
+            writeln!(code, "        ::aoc_lib::register_day!(day{}::{}),", day.day, puzzle).unwrap();
+        }
+    }
+    code += "    ].into_iter().flatten()\n}\n";
+
+    code += "
+/// The year with every puzzle declared in its days.
+pub fn year() -> ::aoc_lib::year::Year {
+    let mut year = ::aoc_lib::year::Year::new(crate::YEAR);
+    year.add_days(days()).expect(\"The puzzles are checked at compile time\");
+    year
+}
+
+#[cfg(test)]
+mod examples {
+    #[test]
+    fn test_examples() {
+        ::aoc_lib::testing::assert_examples(&super::year());
+    }
+}
+";
+
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_puzzles() {
+        let source = "\
+use crate::prelude::*;
+
+create_solution!(13, examples = [
+    { input: \"create_alt_solution!(13, NotThis, \\\"\\\")\" },
+]);
+create_alt_solution!(13, MultipleSolutions, \"Handle Multiple Solutions\");
+    create_alt_solution!(13, NoMatrix, \"No Matrix Equations\");
+
+impl SolutionPart1 for PuzzleSolution {}
+";
+        assert_eq!(find_puzzles(source).unwrap(), ["PuzzleSolution", "MultipleSolutions", "NoMatrix"]);
+    }
+
+    #[test]
+    fn test_find_split_and_hand_written_puzzles() {
+        let source = r##"
+// create_alt_solution!(3, Commented, "");
+/* create_alt_solution!(3, /* nested */ Commented, ""); */
+create_alt_solution!(
+    3,
+    Split,
+    "Split by rustfmt",
+);
+aoc_lib::create_alt_solution!(3, Qualified, r#"a "quoted" name"#);
+const QUOTE: char = '"';
+
+pub struct HandWritten;
+
+impl<'a> aoc_lib::Puzzle for crate::day3::HandWritten where Self: 'a {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+}
+create_solution!(3);
+"##;
+        assert_eq!(find_puzzles(source).unwrap(), ["PuzzleSolution", "Split", "Qualified", "HandWritten"]);
+    }
+
+    #[test]
+    fn test_unregistrable_puzzles() {
+        assert!(find_puzzles("impl<T> Puzzle for Generic<T> {}").is_err());
+        assert!(find_puzzles("macro_rules! p { ($name:ident) => { impl Puzzle for $name {} } }").is_err());
+        assert!(find_puzzles("create_alt_solution!(3);").is_err());
+
+        // `days()` can't name puzzles in nested modules or blocks
+        assert!(find_puzzles("mod inner { create_alt_solution!(3, Nested, \"\"); }").is_err());
+        assert!(find_puzzles("fn f() { struct Local; impl Puzzle for Local {} }").is_err());
+    }
+}
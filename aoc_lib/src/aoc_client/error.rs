@@ -0,0 +1,84 @@
+use std::fmt;
+use chrono::{DateTime, Local, Utc};
+
+/// The input of a puzzle is not cached, and the client may not fetch it from the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MissingInput {
+    pub year: u16,
+    pub day: u8,
+}
+
+/// The inputs of several puzzles are not cached, and the client may not fetch them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingInputs {
+    inputs: Vec<MissingInput>,
+}
+
+impl MissingInputs {
+    /// Collect the missing inputs, sorted and without duplicates.
+    pub fn new(inputs: impl IntoIterator<Item = MissingInput>) -> Self {
+        let mut inputs = inputs.into_iter().collect::<Vec<_>>();
+        inputs.sort_unstable_by_key(|input| (input.year, input.day));
+        inputs.dedup();
+
+        Self { inputs }
+    }
+
+    pub fn inputs(&self) -> &[MissingInput] {
+        &self.inputs
+    }
+}
+
+/// The puzzle is not unlocked yet, so the server is not asked for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotUnlocked {
+    pub year: u16,
+    pub day: u8,
+    pub unlocks_at: DateTime<Utc>,
+}
+
+/// The server refused the session, because it expired or was never valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionExpired;
+
+impl fmt::Display for MissingInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The input of {} day {} is not cached, and can't be fetched in offline mode", self.year, self.day)
+    }
+}
+
+impl fmt::Display for MissingInputs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inputs = self.inputs.iter()
+            .map(|input| format!("{} day {}", input.year, input.day))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "These inputs are not cached, and can't be fetched in offline mode: {}", inputs)
+    }
+}
+
+impl fmt::Display for NotUnlocked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The puzzle of {} day {} is not unlocked yet, it unlocks at {}",
+            self.year,
+            self.day,
+            self.unlocks_at.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S")
+        )
+    }
+}
+
+impl fmt::Display for SessionExpired {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The server rejected the session, it probably expired. Log in to adventofcode.com again and update the session cookie")
+    }
+}
+
+impl std::error::Error for MissingInput {}
+
+impl std::error::Error for MissingInputs {}
+
+impl std::error::Error for NotUnlocked {}
+
+impl std::error::Error for SessionExpired {}
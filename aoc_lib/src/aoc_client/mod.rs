@@ -0,0 +1,620 @@
+use std::time::Duration;
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use reqwest::{Method, RequestBuilder, Response, StatusCode};
+
+use crate::bench::BenchHistory;
+use crate::cache::Cache;
+use crate::calendar::Calendar;
+use crate::credentials::Session;
+use crate::description::{html, ExtractedExample, PuzzleDescription, StoredExamples};
+use crate::leaderboard::Leaderboard;
+use crate::ledger::{AnswerLedger, LedgerCheck};
+use crate::puzzle::SolutionOutput;
+use crate::util::duration::DisplayDuration;
+use crate::{schedule, Part};
+
+mod error;
+mod submit;
+mod throttle;
+
+pub use error::{MissingInput, MissingInputs, NotUnlocked, SessionExpired};
+pub use submit::SubmissionVerdict;
+pub use throttle::RetryPolicy;
+
+use throttle::RateLimiter;
+
+pub struct AocClient {
+    client: reqwest::Client,
+    base_url: String,
+    offline: bool,
+    cache: Cache,
+    limiter: RateLimiter,
+    retry: RetryPolicy,
+}
+
+const USER_AGENT: &str = concat!("aoc-client/", env!("CARGO_PKG_VERSION"));
+const AOC_URL: &str = "https://adventofcode.com";
+/// The default minimum interval between two requests to the server.
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+/// How long a cached puzzle page without part 2 is used before it is fetched again, in case
+/// part 1 was solved elsewhere, e.g. on the website.
+const PUZZLE_PAGE_TTL: Duration = Duration::from_secs(15 * 60);
+
+impl AocClient {
+    pub fn new(session: &Session) -> Self {
+        Self::build(Some(session))
+    }
+
+    /// Create a client which only uses the cache and never contacts the server, so it doesn't
+    /// need a session.
+    pub fn offline() -> Self {
+        Self {
+            offline: true,
+            ..Self::build(None)
+        }
+    }
+
+    fn build(session: Option<&Session>) -> Self {
+        let mut headers = reqwest::header::HeaderMap::new();
+        if let Some(session) = session {
+            headers.insert(reqwest::header::COOKIE, session.header());
+        }
+
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .user_agent(USER_AGENT)
+            .default_headers(headers)
+            .build()
+            .expect("Failed to build reqwest::Client");
+
+        AocClient {
+            client,
+            base_url: AOC_URL.to_string(),
+            offline: false,
+            cache: Cache::new(Cache::default_root()),
+            limiter: RateLimiter::new(MIN_REQUEST_INTERVAL),
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Use a different cache than the one at [`Cache::default_root`].
+    pub fn with_cache(mut self, cache: Cache) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
+    /// Use a different server than the official aoc website, e.g. a local stand-in for testing.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Keep at least this much time between two requests to the server, zero disables the limit.
+    pub fn with_min_request_interval(mut self, interval: Duration) -> Self {
+        self.limiter = RateLimiter::new(interval);
+        self
+    }
+
+    pub fn min_request_interval(&self) -> Duration {
+        self.limiter.min_interval()
+    }
+
+    /// Retry requests which failed for transient reasons, such as connection errors or server errors.
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Whether the client only uses the cache.
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Get the input of the puzzle, from the cache if possible.
+    ///
+    /// In offline mode a cache miss is a [`MissingInput`] error.
+    pub async fn get_challenge(&self, year: u16, day: u8) -> Result<String> {
+        match self.cache.read_input(year, day)
+            .context("Failed to check the cache for the challenge")?
+        {
+            Some(text) => Ok(text),
+            None if self.offline => Err(MissingInput { year, day }.into()),
+            None => self.get_challenge_from_server(year, day).await
+                .context("Failed to fetch challenge from server")
+        }
+    }
+
+    /// Load the answer ledger for the given day, an empty ledger is returned if there is none yet.
+    pub fn get_answer_ledger(&self, year: u16, day: u8) -> Result<AnswerLedger> {
+        self.cache.read_json(year, day, "answers.json", "answer ledger")
+    }
+
+    /// Compare the answer of a run against the answer ledger of the day.
+    pub fn check_output(&self, year: u16, day: u8, part: Part, output: &SolutionOutput) -> Result<LedgerCheck> {
+        let ledger = self.get_answer_ledger(year, day)
+            .context("Failed to load the answer ledger")?;
+
+        Ok(ledger.check_output(part, output))
+    }
+
+    /// Save the answer ledger for the given day.
+    pub fn save_answer_ledger(&self, year: u16, day: u8, ledger: &AnswerLedger) -> Result<()> {
+        self.cache.write_json(year, day, "answers.json", "answer ledger", ledger)
+    }
+
+    /// Load the benchmark history for the given day, an empty history is returned if there is
+    /// none yet.
+    pub fn get_bench_history(&self, year: u16, day: u8) -> Result<BenchHistory> {
+        self.cache.read_json(year, day, "benchmarks.json", "benchmark history")
+    }
+
+    /// Save the benchmark history for the given day.
+    pub fn save_bench_history(&self, year: u16, day: u8, history: &BenchHistory) -> Result<()> {
+        self.cache.write_json(year, day, "benchmarks.json", "benchmark history", history)
+    }
+
+    async fn get_challenge_from_server(&self, year: u16, day: u8) -> Result<String> {
+        check_unlocked(year, day)?;
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let res = self.send(self.client.request(Method::GET, &url), true).await?;
+        let res = check_session(res)?
+            .text()
+            .await?;
+
+        self.cache.write_input(year, day, &res)
+            .unwrap_or_else(|err| {
+                crate::io::print_warning(format_args!("{:?}",
+                    anyhow::Error::new(err)
+                        .context("Failed to save the challenge to the cache")
+                ));
+            });
+
+        Ok(res)
+    }
+
+    /// Get the html page of the puzzle description, from the cache if possible.
+    pub async fn get_puzzle_page(&self, year: u16, day: u8) -> Result<String> {
+        match self.cache.read_text(year, day, Cache::PUZZLE)
+            .context("Failed to check the cache for the puzzle page")?
+        {
+            Some(html) => Ok(html),
+            None if self.offline => bail!("The puzzle page of {} day {} is not cached, and can't be fetched in offline mode", year, day),
+            None => self.fetch_puzzle_page(year, day).await,
+        }
+    }
+
+    /// Get the description of the puzzle.
+    ///
+    /// The cached page is fetched again if the answer ledger knows about solved parts which it
+    /// doesn't show yet, e.g. part 2 after part 1 was solved. A page without part 2 is also
+    /// fetched again once it is older than 15 minutes, as the ledger only knows about the answers
+    /// submitted with this client.
+    pub async fn get_puzzle_description(&self, year: u16, day: u8) -> Result<PuzzleDescription> {
+        let description = PuzzleDescription::parse(&self.get_puzzle_page(year, day).await?);
+        if self.offline {
+            return Ok(description);
+        }
+
+        let ledger = self.get_answer_ledger(year, day)?;
+        let stale = Part::ALL.into_iter()
+            .any(|part| ledger.part(part).accepted().is_some() && description.answer(part).is_none());
+        let outdated = description.parts().len() < Part::ALL.len()
+            && std::fs::metadata(self.cache.path(year, day, Cache::PUZZLE))
+                .and_then(|metadata| metadata.modified())
+                .is_ok_and(|modified| modified.elapsed().unwrap_or_default() >= PUZZLE_PAGE_TTL);
+        if !stale && !outdated {
+            return Ok(description);
+        }
+
+        Ok(PuzzleDescription::parse(&self.fetch_puzzle_page(year, day).await?))
+    }
+
+    /// Get the examples of the puzzle description.
+    ///
+    /// The examples are extracted once per unlocked part and stored in the cache, where they can
+    /// be corrected by hand if the extraction picked the wrong answers.
+    pub async fn get_examples(&self, year: u16, day: u8) -> Result<Vec<ExtractedExample>> {
+        let description = self.get_puzzle_description(year, day).await?;
+        let stored: StoredExamples = self.cache.read_json(year, day, "examples.json", "examples")?;
+        if stored.parts > 0 && stored.parts >= description.parts().len() {
+            return Ok(stored.examples);
+        }
+
+        let stored = StoredExamples {
+            parts: description.parts().len(),
+            examples: description.examples(),
+        };
+        self.cache.write_json(year, day, "examples.json", "examples", &stored)?;
+
+        Ok(stored.examples)
+    }
+
+    /// Fetch the html page of the puzzle description from the server and update the cache, e.g.
+    /// to see part 2 once part 1 is solved.
+    pub async fn fetch_puzzle_page(&self, year: u16, day: u8) -> Result<String> {
+        if self.offline {
+            bail!("The puzzle page can't be fetched in offline mode");
+        }
+        check_unlocked(year, day)?;
+
+        let url = format!("{}/{}/day/{}", self.base_url, year, day);
+        let res = self.send(self.client.request(Method::GET, &url), true).await
+            .context("Failed to fetch the puzzle page")?;
+        let html = check_session(res)
+            .context("Failed to fetch the puzzle page")?
+            .text()
+            .await
+            .context("Failed to read the puzzle page")?;
+
+        self.cache.write_text(year, day, Cache::PUZZLE, &html)
+            .context("Failed to save the puzzle page to the cache")?;
+
+        Ok(html)
+    }
+
+    /// Get the stars earned in the year from the calendar page.
+    ///
+    /// A calendar fetched within the last 15 minutes is taken from the cache, in offline mode the
+    /// cached one is used however old.
+    pub async fn get_calendar(&self, year: u16) -> Result<Calendar> {
+        let path = self.cache.year_path(year, "calendar.json");
+        let cached = Cache::read_json_at::<Calendar>(&path, "calendar")?;
+
+        let now = Utc::now();
+        match cached {
+            Some(cached) if self.offline || cached.is_fresh(now) => return Ok(cached),
+            None if self.offline => bail!("The calendar of {} is not cached, and can't be fetched in offline mode", year),
+            _ => {}
+        }
+
+        let url = format!("{}/{}", self.base_url, year);
+        let res = self.send(self.client.request(Method::GET, &url), true).await
+            .context("Failed to fetch the calendar")?;
+        let html = check_session(res)
+            .context("Failed to fetch the calendar")?
+            .text()
+            .await
+            .context("Failed to read the calendar")?;
+        check_logged_in(&html)
+            .context("Failed to fetch the calendar")?;
+        let calendar = Calendar::parse(&html).with_fetched_at(now);
+
+        Cache::write_json_at(&path, "calendar", &calendar)?;
+
+        Ok(calendar)
+    }
+
+    /// Add a star to the cached calendar, so it is up to date without fetching it again.
+    fn record_star(&self, year: u16, day: u8, part: Part) -> Result<()> {
+        let path = self.cache.year_path(year, "calendar.json");
+        let Some(mut calendar) = Cache::read_json_at::<Calendar>(&path, "calendar")? else {
+            return Ok(());
+        };
+
+        calendar.record_star(day, part);
+        Cache::write_json_at(&path, "calendar", &calendar)
+    }
+
+    /// Get the private leaderboard with the given id.
+    ///
+    /// A leaderboard fetched within the last 15 minutes is taken from the cache, as the aoc
+    /// website asks not to poll it more often. In offline mode the cached one is used, however old.
+    pub async fn get_private_leaderboard(&self, year: u16, id: u64) -> Result<Leaderboard> {
+        let path = self.cache.year_path(year, &format!("leaderboard-{}.json", id));
+        let cached = Cache::read_json_at::<Leaderboard>(&path, "leaderboard")?;
+
+        let now = Utc::now();
+        match cached {
+            Some(cached) if self.offline || cached.is_fresh(now) => return Ok(cached),
+            None if self.offline => bail!("The leaderboard {} of {} is not cached, and can't be fetched in offline mode", id, year),
+            _ => {}
+        }
+
+        let url = format!("{}/{}/leaderboard/private/view/{}.json", self.base_url, year, id);
+        let res = self.send(self.client.request(Method::GET, &url), true).await
+            .context("Failed to fetch the leaderboard")?;
+        let text = check_session(res)
+            .context("Failed to fetch the leaderboard")?
+            .text()
+            .await
+            .context("Failed to read the leaderboard")?;
+        let leaderboard = serde_json::from_str::<Leaderboard>(&text)
+            .context("The leaderboard is malformed, make sure you are a member of it")?
+            .with_fetched_at(now);
+
+        Cache::write_json_at(&path, "leaderboard", &leaderboard)?;
+
+        Ok(leaderboard)
+    }
+
+    /// Submit an answer for the given part of the puzzle and parse the server's verdict.
+    pub async fn submit_answer(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<SubmissionVerdict> {
+        if self.offline {
+            bail!("Answers can't be submitted in offline mode");
+        }
+
+        check_unlocked(year, day)?;
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.level().to_string();
+        let request = self.client.request(Method::POST, &url)
+            .form(&[("level", level.as_str()), ("answer", answer)]);
+        // The answer may have been checked even if the server failed afterward, so a
+        // submission is only retried if it never reached the server
+        let res = self.send(request, false).await
+            .context("Failed to submit the answer")?;
+        let res = check_session(res)
+            .context("Failed to submit the answer")?
+            .text()
+            .await
+            .context("Failed to read the answer response")?;
+
+        let verdict = SubmissionVerdict::from_response(&res)?;
+        if verdict == SubmissionVerdict::Correct {
+            if let Err(err) = self.record_star(year, day, part) {
+                crate::io::print_warning(format_args!("{:?}", err.context("Failed to update the cached calendar")));
+            }
+        }
+
+        Ok(verdict)
+    }
+
+    /// Send the request once the rate limit allows it, retrying transient failures.
+    ///
+    /// Requests which are not idempotent are only retried if the connection failed.
+    async fn send(&self, request: RequestBuilder, idempotent: bool) -> Result<Response> {
+        // The server limits the machine, not the account, so every account shares the state
+        let state = throttle::state_path(self.cache.shared_root());
+        let mut retry = 0;
+        loop {
+            let attempt = request.try_clone().expect("Requests without a streaming body can be cloned");
+            self.limiter.acquire(&state).await;
+
+            let res = attempt.send().await;
+            let transient = match &res {
+                Ok(res) => idempotent && res.status().is_server_error(),
+                Err(err) => err.is_connect() || (idempotent && err.is_timeout()),
+            };
+            if !transient || retry >= self.retry.max_retries() {
+                return Ok(res?);
+            }
+
+            retry += 1;
+            let backoff = self.retry.backoff(retry);
+            let reason = match &res {
+                Ok(res) => res.status().to_string(),
+                Err(err) => err.to_string(),
+            };
+            crate::io::print_warning(format_args!(
+                "Request failed ({}), retry {} of {} in {}",
+                reason, retry, self.retry.max_retries(), DisplayDuration(backoff)
+            ));
+            tokio::time::sleep(backoff).await;
+        }
+    }
+}
+
+/// Refuse to ask the server about puzzles which don't exist or are not unlocked yet.
+fn check_unlocked(year: u16, day: u8) -> Result<()> {
+    let Some(unlocks_at) = schedule::unlock_time(year, day) else {
+        bail!("There is no puzzle for day {} of {}", day, year);
+    };
+    if Utc::now() < unlocks_at {
+        return Err(NotUnlocked { year, day, unlocks_at }.into());
+    }
+
+    Ok(())
+}
+
+/// Turn the responses the server gives for a missing or expired session into [`SessionExpired`].
+///
+/// Without a valid session the server redirects away from the answer form and answers input
+/// requests with a 400.
+fn check_session(res: Response) -> Result<Response> {
+    let status = res.status();
+    if status.is_redirection() || status == StatusCode::BAD_REQUEST || status == StatusCode::UNAUTHORIZED {
+        return Err(SessionExpired.into());
+    }
+
+    Ok(res.error_for_status()?)
+}
+
+/// Turn a page served to a logged out user into [`SessionExpired`].
+///
+/// Pages like the calendar are public, only the header shows whether the session is valid: it
+/// has the name of the user when logged in, and a link to log in otherwise.
+fn check_logged_in(page: &str) -> Result<()> {
+    let logged_in = html::parse(page)
+        .find_all("div")
+        .into_iter()
+        .any(|div| div.has_class("user"));
+    if !logged_in {
+        return Err(SessionExpired.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use super::*;
+
+    /// Serve canned responses to consecutive connections on a local port, returning the base url
+    /// and the raw requests.
+    fn serve(responses: Vec<(&'static str, &'static str)>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind local listener");
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses.into_iter()
+                .map(|(status, body)| {
+                    let (mut stream, _) = listener.accept().expect("Failed to accept connection");
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                    let mut request = String::new();
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                            content_length = len.trim().parse().unwrap();
+                        }
+                        request.push_str(&line);
+                        if line == "\r\n" {
+                            break;
+                        }
+                    }
+                    let mut req_body = vec![0; content_length];
+                    reader.read_exact(&mut req_body).unwrap();
+                    request.push_str(&String::from_utf8(req_body).unwrap());
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    ).unwrap();
+
+                    request
+                })
+                .collect()
+        });
+
+        (base_url, handle)
+    }
+
+    fn serve_once(status: &'static str, body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        serve(vec![(status, body)])
+    }
+
+    fn test_client(base_url: String) -> AocClient {
+        let session = Session::parse(&"ab".repeat(64)).unwrap();
+        AocClient::new(&session)
+            .with_base_url(base_url)
+            .with_min_request_interval(Duration::ZERO)
+    }
+
+    #[tokio::test]
+    async fn test_submit_answer() {
+        let (base_url, handle) = serve_once(
+            "200 OK",
+            "<html><body><main><article><p>That's not the right answer; your answer is too low.</p></article></main></body></html>"
+        );
+        let client = test_client(base_url);
+
+        let verdict = client.submit_answer(2024, 3, Part::Part2, "1234").await
+            .expect("Submission should succeed");
+        assert_eq!(verdict, SubmissionVerdict::TooLow);
+
+        let request = handle.join().unwrap().remove(0);
+        assert!(request.starts_with("POST /2024/day/3/answer "), "Unexpected request: {}", request);
+        assert!(request.contains(&format!("session={}", "ab".repeat(64))), "Session cookie missing: {}", request);
+        assert!(request.ends_with("level=2&answer=1234"), "Unexpected form body: {}", request);
+    }
+
+    #[tokio::test]
+    async fn test_expired_session() {
+        let (base_url, _) = serve_once("302 Found", "");
+        let client = test_client(base_url);
+
+        let err = client.submit_answer(2024, 3, Part::Part1, "1").await.unwrap_err();
+        assert_eq!(err.downcast_ref::<SessionExpired>(), Some(&SessionExpired));
+    }
+
+    #[tokio::test]
+    async fn test_logged_out_calendar() {
+        let (base_url, _) = serve(vec![
+            ("200 OK", "<html><header><div><a href=\"/2024/auth/login\">[Log In]</a></div></header><main><pre class=\"calendar\"><a class=\"calendar-day1\"></a></pre></main></html>"),
+            ("200 OK", "<html><header><div class=\"user\">someone</div></header><main><pre class=\"calendar\"><a class=\"calendar-day1 calendar-verycomplete\"></a></pre></main></html>"),
+        ]);
+        let cache = Cache::new(std::env::temp_dir().join(format!("aoc-client-calendar-{}", std::process::id())));
+        let client = test_client(base_url).with_cache(cache.clone());
+
+        let err = client.get_calendar(2024).await.unwrap_err();
+        assert_eq!(err.downcast_ref::<SessionExpired>(), Some(&SessionExpired));
+        assert!(!cache.year_path(2024, "calendar.json").exists(), "The logged out calendar was cached");
+
+        let calendar = client.get_calendar(2024).await.unwrap();
+        assert_eq!(calendar.stars(1), 2);
+
+        std::fs::remove_dir_all(cache.root()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_refresh_puzzle_page() {
+        let part1 = "<main><article class=\"day-desc\"><h2>--- Day 1: Test ---</h2><p>One</p></article></main>";
+        let both = "<main><article class=\"day-desc\"><h2>--- Day 1: Test ---</h2><p>One</p></article>\
+            <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Two</p></article></main>";
+        let (base_url, handle) = serve_once("200 OK", both);
+        let cache = Cache::new(std::env::temp_dir().join(format!("aoc-client-refresh-{}", std::process::id())));
+        let client = test_client(base_url).with_cache(cache.clone());
+
+        // A recently cached page is used as is
+        cache.write_text(2015, 1, Cache::PUZZLE, part1).unwrap();
+        assert_eq!(client.get_puzzle_description(2015, 1).await.unwrap().parts().len(), 1);
+
+        let path = cache.path(2015, 1, Cache::PUZZLE);
+        let modified = std::time::SystemTime::now() - PUZZLE_PAGE_TTL;
+        std::fs::File::options().write(true).open(&path).unwrap().set_modified(modified).unwrap();
+        assert_eq!(client.get_puzzle_description(2015, 1).await.unwrap().parts().len(), 2);
+        assert_eq!(handle.join().unwrap().len(), 1);
+
+        std::fs::remove_dir_all(cache.root()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_shared_by_accounts() {
+        let (base_url, _) = serve(vec![("200 OK", "alice\n"), ("200 OK", "bob\n")]);
+        let cache = Cache::new(std::env::temp_dir().join(format!("aoc-client-accounts-{}", std::process::id())));
+        let interval = Duration::from_millis(500);
+        let client = |name| test_client(base_url.clone())
+            .with_cache(cache.for_account(name).unwrap())
+            .with_min_request_interval(interval);
+
+        let start = std::time::Instant::now();
+        assert_eq!(client("alice").get_challenge(2015, 1).await.unwrap(), "alice\n");
+        assert_eq!(client("bob").get_challenge(2015, 1).await.unwrap(), "bob\n");
+        assert!(start.elapsed() >= interval, "The second account was not throttled");
+
+        std::fs::remove_dir_all(cache.root()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_retry_server_error() {
+        let (base_url, handle) = serve(vec![("503 Service Unavailable", ""), ("200 OK", "1 2 3\n")]);
+        let cache = Cache::new(std::env::temp_dir().join(format!("aoc-client-retry-{}", std::process::id())));
+        let client = test_client(base_url)
+            .with_cache(cache.clone())
+            .with_retry_policy(RetryPolicy::default().with_initial_backoff(Duration::from_millis(1)));
+
+        assert_eq!(client.get_challenge(2015, 1).await.unwrap(), "1 2 3\n");
+        assert_eq!(handle.join().unwrap().len(), 2);
+
+        std::fs::remove_dir_all(cache.root()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_not_unlocked() {
+        let client = test_client("http://127.0.0.1:0".to_string());
+        let err = client.submit_answer(9999, 1, Part::Part1, "1").await.unwrap_err();
+        assert!(err.downcast_ref::<NotUnlocked>().is_some(), "Unexpected error: {:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_offline_cache_miss() {
+        let client = AocClient::offline();
+        let err = client.get_challenge(1999, 1).await.unwrap_err();
+        assert_eq!(err.downcast_ref::<MissingInput>(), Some(&MissingInput { year: 1999, day: 1 }));
+        assert!(client.submit_answer(1999, 1, Part::Part1, "1").await.is_err());
+    }
+}
@@ -0,0 +1,184 @@
+use std::fmt;
+use std::time::Duration;
+use anyhow::{bail, Result};
+
+use crate::util::duration::DisplayDuration;
+
+/// The verdict the aoc server gave for a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmissionVerdict {
+    /// The answer was accepted.
+    Correct,
+    /// The answer was wrong, and the server hinted that it is too high.
+    TooHigh,
+    /// The answer was wrong, and the server hinted that it is too low.
+    TooLow,
+    /// The answer was wrong, without any further hint.
+    Wrong,
+    /// An answer was submitted too recently, the answer was not checked.
+    RateLimited {
+        /// The time left until another answer may be submitted, if the server reported it.
+        wait: Option<Duration>,
+    },
+    /// The part was already solved (or is not unlocked yet), the answer was not checked.
+    AlreadySolved,
+}
+
+impl SubmissionVerdict {
+    /// Parse the verdict from the html page returned by the answer endpoint.
+    pub fn from_response(html: &str) -> Result<Self> {
+        let article = extract_article(html);
+        let text = strip_tags(article);
+
+        if text.contains("That's the right answer") {
+            return Ok(Self::Correct);
+        }
+        if text.contains("You gave an answer too recently") {
+            return Ok(Self::RateLimited {
+                wait: parse_wait(&text),
+            });
+        }
+        if text.contains("You don't seem to be solving the right level") {
+            return Ok(Self::AlreadySolved);
+        }
+        if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                return Ok(Self::TooHigh);
+            }
+            if text.contains("your answer is too low") {
+                return Ok(Self::TooLow);
+            }
+            return Ok(Self::Wrong);
+        }
+
+        bail!("Unrecognized response from the answer endpoint: {}", text.trim())
+    }
+
+    /// Whether the server actually checked the answer.
+    pub fn was_checked(&self) -> bool {
+        match self {
+            Self::Correct | Self::TooHigh | Self::TooLow | Self::Wrong => true,
+            Self::RateLimited { .. } | Self::AlreadySolved => false,
+        }
+    }
+}
+
+impl fmt::Display for SubmissionVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "That's the right answer!"),
+            Self::TooHigh => write!(f, "That's not the right answer, it is too high."),
+            Self::TooLow => write!(f, "That's not the right answer, it is too low."),
+            Self::Wrong => write!(f, "That's not the right answer."),
+            Self::RateLimited { wait: Some(wait) } => {
+                write!(f, "You gave an answer too recently, {} left to wait.", DisplayDuration(*wait))
+            }
+            Self::RateLimited { wait: None } => write!(f, "You gave an answer too recently."),
+            Self::AlreadySolved => write!(f, "This part was already solved or is not unlocked yet."),
+        }
+    }
+}
+
+/// Get the contents of the `<article>` element, or the whole page if there is none.
+fn extract_article(html: &str) -> &str {
+    let Some(start) = html.find("<article") else {
+        return html;
+    };
+    let end = html[start..].find("</article>")
+        .map(|end| start + end)
+        .unwrap_or(html.len());
+
+    &html[start..end]
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut res = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => res.push(c),
+            _ => {}
+        }
+    }
+
+    res.replace("&apos;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Parse the wait time from a sentence like "You have 1m 3s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+    for part in text[start..end].split_whitespace() {
+        let (num, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let num: u64 = num.parse().ok()?;
+        secs += match unit {
+            "h" => num * 60 * 60,
+            "m" => num * 60,
+            "s" => num,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{}</p></article></main></body></html>", article)
+    }
+
+    #[test]
+    fn test_parse_correct() {
+        let html = page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to finding the Chief Historian.");
+        assert_eq!(SubmissionVerdict::from_response(&html).unwrap(), SubmissionVerdict::Correct);
+    }
+
+    #[test]
+    fn test_parse_too_high() {
+        let html = page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.");
+        assert_eq!(SubmissionVerdict::from_response(&html).unwrap(), SubmissionVerdict::TooHigh);
+    }
+
+    #[test]
+    fn test_parse_too_low() {
+        let html = page("That's not the right answer; your answer is too low.  Please wait one minute before trying again.");
+        assert_eq!(SubmissionVerdict::from_response(&html).unwrap(), SubmissionVerdict::TooLow);
+    }
+
+    #[test]
+    fn test_parse_wrong() {
+        let html = page("That's not the right answer.  If you're stuck, make sure you're using the full input data.");
+        assert_eq!(SubmissionVerdict::from_response(&html).unwrap(), SubmissionVerdict::Wrong);
+    }
+
+    #[test]
+    fn test_parse_rate_limited() {
+        let html = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 3s left to wait.");
+        assert_eq!(
+            SubmissionVerdict::from_response(&html).unwrap(),
+            SubmissionVerdict::RateLimited { wait: Some(Duration::from_secs(63)) }
+        );
+    }
+
+    #[test]
+    fn test_parse_already_solved() {
+        let html = page("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2024/day/1\">[Return to Day 1]</a>");
+        assert_eq!(SubmissionVerdict::from_response(&html).unwrap(), SubmissionVerdict::AlreadySolved);
+    }
+
+    #[test]
+    fn test_parse_unknown() {
+        assert!(SubmissionVerdict::from_response(&page("Something else entirely")).is_err());
+    }
+}
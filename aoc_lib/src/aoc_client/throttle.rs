@@ -0,0 +1,149 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Keeps a minimum interval between requests to the server.
+///
+/// The time of the last request is persisted, so consecutive runs of the binary are throttled as
+/// well.
+#[derive(Debug)]
+pub(super) struct RateLimiter {
+    min_interval: Duration,
+    /// The time the last request was sent, or is scheduled to be sent.
+    last: Mutex<Option<SystemTime>>,
+}
+
+impl RateLimiter {
+    pub(super) fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            last: Mutex::new(None),
+        }
+    }
+
+    pub(super) fn min_interval(&self) -> Duration {
+        self.min_interval
+    }
+
+    /// Wait until the next request may be sent, the state file holds the time of the last request
+    /// of any process.
+    pub(super) async fn acquire(&self, state: &Path) {
+        if self.min_interval.is_zero() {
+            return;
+        }
+
+        let scheduled = {
+            let mut last = self.last.lock().expect("Rate limiter lock poisoned");
+            let persisted = read_state(state);
+            let previous = match (*last, persisted) {
+                (Some(a), Some(b)) => Some(a.max(b)),
+                (a, b) => a.or(b),
+            };
+
+            let now = SystemTime::now();
+            let scheduled = previous
+                .map(|previous| previous + self.min_interval)
+                .filter(|&next| next > now)
+                .unwrap_or(now);
+            *last = Some(scheduled);
+            write_state(state, scheduled);
+
+            scheduled
+        };
+
+        if let Ok(wait) = scheduled.duration_since(SystemTime::now()) {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+fn read_state(path: &Path) -> Option<SystemTime> {
+    let millis = fs::read_to_string(path).ok()?.trim().parse::<u64>().ok()?;
+    Some(UNIX_EPOCH + Duration::from_millis(millis))
+}
+
+fn write_state(path: &Path, time: SystemTime) {
+    let millis = time.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+    let res = path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, millis.to_string()));
+
+    if let Err(err) = res {
+        crate::io::print_warning(format_args!("Failed to save the rate limit state: {}", err));
+    }
+}
+
+/// How often, and after how long, requests which failed for transient reasons are retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy which never retries.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// The wait before the first retry, it doubles with every further retry.
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// The wait before the given retry, starting at 1.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+}
+
+/// The file the rate limit state is kept in, inside the cache root.
+pub(super) fn state_path(cache_root: &Path) -> PathBuf {
+    cache_root.join("last-request")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy::default()
+            .with_initial_backoff(Duration::from_secs(1))
+            .with_max_backoff(Duration::from_secs(5));
+
+        let backoffs = (1..=5).map(|retry| policy.backoff(retry).as_secs()).collect::<Vec<_>>();
+        assert_eq!(backoffs, [1, 2, 4, 5, 5]);
+    }
+}
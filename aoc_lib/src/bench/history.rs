@@ -0,0 +1,416 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::process::Command;
+use std::time::Duration;
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::util::duration::DisplayDuration;
+use crate::Part;
+use super::{BenchReport, PhaseReport};
+
+/// Changes smaller than this fraction of the previous mean are never reported.
+const NOISE_THRESHOLD: f64 = 0.02;
+
+/// The t-statistic above which a change is significant, roughly a 99% confidence level.
+const SIGNIFICANCE_THRESHOLD: f64 = 2.58;
+
+/// How many runs of each part and alternative the history keeps, besides the baselines.
+const MAX_RUNS: usize = 20;
+
+/// The recorded benchmark runs of a single day.
+///
+/// Only the latest 20 runs of each part and alternative are kept, and the latest run
+/// saved as each baseline.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BenchHistory {
+    #[serde(default)]
+    runs: Vec<BenchRecord>,
+}
+
+/// The benchmark results of a single part, as recorded in the history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchRecord {
+    timestamp: DateTime<Utc>,
+    /// The git revision of the solutions, if they were benchmarked inside a git repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    revision: Option<String>,
+    /// The name of the baseline this run was saved as.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    baseline: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    alt: Option<String>,
+    part: Part,
+    parse: PhaseStats,
+    solve: PhaseStats,
+    #[serde(default)]
+    shared_parse: bool,
+}
+
+/// The summary of the samples of a single phase, enough to compare it against later runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhaseStats {
+    samples: usize,
+    mean: Duration,
+    std_dev: Duration,
+    median: Duration,
+    min: Duration,
+}
+
+/// The difference between two runs of the same part.
+#[derive(Debug, Clone)]
+pub struct BenchComparison {
+    previous: BenchRecord,
+    parse: PhaseChange,
+    solve: PhaseChange,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PhaseChange {
+    before: Duration,
+    after: Duration,
+    verdict: ChangeVerdict,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeVerdict {
+    Improved,
+    Regressed,
+    /// The difference is within the noise of the measurements.
+    Unchanged,
+}
+
+impl BenchHistory {
+    pub fn runs(&self) -> &[BenchRecord] {
+        &self.runs
+    }
+
+    /// Add a run, dropping the runs which are too old to be kept.
+    pub fn record(&mut self, record: BenchRecord) {
+        self.runs.push(record);
+        self.prune();
+    }
+
+    fn prune(&mut self) {
+        let mut recent = HashMap::new();
+        let mut baselines = HashSet::new();
+        let mut keep = self.runs.iter()
+            .rev()
+            .map(|run| {
+                let count = recent.entry((run.alt.clone(), run.part)).or_insert(0);
+                *count += 1;
+                let latest_baseline = run.baseline.as_ref()
+                    .is_some_and(|name| baselines.insert((name.clone(), run.alt.clone(), run.part)));
+
+                *count <= MAX_RUNS || latest_baseline
+            })
+            .collect::<Vec<_>>();
+
+        keep.reverse();
+        let mut keep = keep.into_iter();
+        self.runs.retain(|_| keep.next().unwrap_or(true));
+    }
+
+    /// The latest run of the given part and alternative.
+    pub fn previous(&self, alt: Option<&str>, part: Part) -> Option<&BenchRecord> {
+        self.runs.iter()
+            .rev()
+            .find(|run| run.is_same_solution(alt, part))
+    }
+
+    /// The latest run of the given part and alternative which was saved as the named baseline.
+    pub fn baseline(&self, name: &str, alt: Option<&str>, part: Part) -> Option<&BenchRecord> {
+        self.runs.iter()
+            .rev()
+            .filter(|run| run.baseline.as_deref() == Some(name))
+            .find(|run| run.is_same_solution(alt, part))
+    }
+}
+
+impl BenchRecord {
+    /// Record the report of a run which just finished.
+    pub fn new(report: &PhaseReport, alt: Option<&str>, part: Part, revision: Option<String>) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            revision,
+            baseline: None,
+            alt: alt.map(str::to_string),
+            part,
+            parse: PhaseStats::from(report.parse()),
+            solve: PhaseStats::from(report.solve()),
+            shared_parse: report.shared_parse(),
+        }
+    }
+
+    /// Save the run as the named baseline, so later runs can be compared against it.
+    pub fn with_baseline(mut self, name: impl Into<String>) -> Self {
+        self.baseline = Some(name.into());
+        self
+    }
+
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    pub fn revision(&self) -> Option<&str> {
+        self.revision.as_deref()
+    }
+
+    pub fn baseline(&self) -> Option<&str> {
+        self.baseline.as_deref()
+    }
+
+    pub fn alt(&self) -> Option<&str> {
+        self.alt.as_deref()
+    }
+
+    pub fn part(&self) -> Part {
+        self.part
+    }
+
+    pub fn parse(&self) -> &PhaseStats {
+        &self.parse
+    }
+
+    pub fn solve(&self) -> &PhaseStats {
+        &self.solve
+    }
+
+    pub fn shared_parse(&self) -> bool {
+        self.shared_parse
+    }
+
+    /// Compare this run against an earlier run of the same part.
+    pub fn compare(&self, previous: &BenchRecord) -> BenchComparison {
+        BenchComparison {
+            previous: previous.clone(),
+            parse: PhaseChange::new(&previous.parse, &self.parse),
+            solve: PhaseChange::new(&previous.solve, &self.solve),
+        }
+    }
+
+    fn is_same_solution(&self, alt: Option<&str>, part: Part) -> bool {
+        self.alt.as_deref() == alt && self.part == part
+    }
+}
+
+impl PhaseStats {
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+
+    pub fn mean(&self) -> Duration {
+        self.mean
+    }
+
+    pub fn std_dev(&self) -> Duration {
+        self.std_dev
+    }
+
+    pub fn median(&self) -> Duration {
+        self.median
+    }
+
+    pub fn min(&self) -> Duration {
+        self.min
+    }
+}
+
+impl From<&BenchReport> for PhaseStats {
+    fn from(report: &BenchReport) -> Self {
+        Self {
+            samples: report.samples().len(),
+            mean: report.mean(),
+            std_dev: report.std_dev(),
+            median: report.median(),
+            min: report.min(),
+        }
+    }
+}
+
+impl BenchComparison {
+    /// The run which was compared against.
+    pub fn previous(&self) -> &BenchRecord {
+        &self.previous
+    }
+
+    pub fn parse(&self) -> PhaseChange {
+        self.parse
+    }
+
+    pub fn solve(&self) -> PhaseChange {
+        self.solve
+    }
+
+    /// Whether any phase got significantly slower.
+    pub fn is_regression(&self) -> bool {
+        self.parse.verdict == ChangeVerdict::Regressed || self.solve.verdict == ChangeVerdict::Regressed
+    }
+}
+
+impl PhaseChange {
+    /// Compare the means of both runs using Welch's t-test.
+    fn new(before: &PhaseStats, after: &PhaseStats) -> Self {
+        let (mean1, mean2) = (before.mean.as_secs_f64(), after.mean.as_secs_f64());
+        let (var1, var2) = (before.std_dev.as_secs_f64().powi(2), after.std_dev.as_secs_f64().powi(2));
+        let std_err = (var1 / before.samples.max(1) as f64 + var2 / after.samples.max(1) as f64).sqrt();
+
+        let diff = mean2 - mean1;
+        let significant = if std_err > 0.0 {
+            (diff / std_err).abs() > SIGNIFICANCE_THRESHOLD
+        } else {
+            diff != 0.0
+        };
+        let noticeable = mean1 > 0.0 && (diff / mean1).abs() > NOISE_THRESHOLD;
+
+        let verdict = match (significant && noticeable, diff > 0.0) {
+            (false, _) => ChangeVerdict::Unchanged,
+            (true, true) => ChangeVerdict::Regressed,
+            (true, false) => ChangeVerdict::Improved,
+        };
+
+        Self {
+            before: before.mean,
+            after: after.mean,
+            verdict,
+        }
+    }
+
+    pub fn before(&self) -> Duration {
+        self.before
+    }
+
+    pub fn after(&self) -> Duration {
+        self.after
+    }
+
+    pub fn verdict(&self) -> ChangeVerdict {
+        self.verdict
+    }
+
+    /// The relative change of the mean, e.g. `0.1` if the run got 10% slower.
+    pub fn relative(&self) -> f64 {
+        if self.before.is_zero() {
+            return 0.0;
+        }
+        self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0
+    }
+}
+
+impl fmt::Display for PhaseChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = match self.verdict {
+            ChangeVerdict::Improved => "improved",
+            ChangeVerdict::Regressed => "regressed",
+            ChangeVerdict::Unchanged => "no significant change",
+        };
+
+        write!(
+            f,
+            "{} -> {} ({:+.1}%, {})",
+            DisplayDuration(self.before),
+            DisplayDuration(self.after),
+            self.relative() * 100.0,
+            verdict,
+        )
+    }
+}
+
+impl fmt::Display for BenchRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.timestamp.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S"))?;
+        if let Some(revision) = &self.revision {
+            write!(f, " at {}", revision)?;
+        }
+        if let Some(baseline) = &self.baseline {
+            write!(f, " (baseline \"{}\")", baseline)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The git revision of the working directory, `None` if it is not inside a git repository.
+///
+/// Uncommitted changes are marked with a `-dirty` suffix.
+pub fn git_revision() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+
+    String::from_utf8(output.stdout)
+        .ok()
+        .map(|revision| revision.trim().to_string())
+        .filter(|revision| !revision.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(samples: usize, mean_us: u64, std_dev_us: u64) -> PhaseStats {
+        PhaseStats {
+            samples,
+            mean: Duration::from_micros(mean_us),
+            std_dev: Duration::from_micros(std_dev_us),
+            median: Duration::from_micros(mean_us),
+            min: Duration::from_micros(mean_us),
+        }
+    }
+
+    #[test]
+    fn test_change_verdict() {
+        let before = stats(100, 1000, 50);
+        assert_eq!(PhaseChange::new(&before, &stats(100, 1200, 50)).verdict(), ChangeVerdict::Regressed);
+        assert_eq!(PhaseChange::new(&before, &stats(100, 800, 50)).verdict(), ChangeVerdict::Improved);
+        // Significant, but below the noise threshold
+        assert_eq!(PhaseChange::new(&before, &stats(100, 1015, 1)).verdict(), ChangeVerdict::Unchanged);
+        // Large, but not significant with that much variance
+        assert_eq!(PhaseChange::new(&stats(10, 1000, 2000), &stats(10, 1500, 2000)).verdict(), ChangeVerdict::Unchanged);
+    }
+
+    #[test]
+    fn test_previous_and_baseline() {
+        let report = PhaseReport::new(
+            BenchReport::from_samples(vec![Duration::from_micros(10)]),
+            BenchReport::from_samples(vec![Duration::from_micros(20)]),
+        );
+
+        let mut history = BenchHistory::default();
+        history.record(BenchRecord::new(&report, None, Part::Part1, None).with_baseline("main"));
+        history.record(BenchRecord::new(&report, None, Part::Part1, Some("abc".to_string())));
+        history.record(BenchRecord::new(&report, Some("Alt"), Part::Part1, None));
+
+        assert_eq!(history.previous(None, Part::Part1).unwrap().revision(), Some("abc"));
+        assert_eq!(history.previous(Some("Alt"), Part::Part1).unwrap().alt(), Some("Alt"));
+        assert!(history.previous(None, Part::Part2).is_none());
+        assert_eq!(history.baseline("main", None, Part::Part1).unwrap().baseline(), Some("main"));
+        assert!(history.baseline("main", Some("Alt"), Part::Part1).is_none());
+    }
+
+    #[test]
+    fn test_prune() {
+        let report = PhaseReport::new(
+            BenchReport::from_samples(vec![Duration::from_micros(10)]),
+            BenchReport::from_samples(vec![Duration::from_micros(20)]),
+        );
+
+        let mut history = BenchHistory::default();
+        history.record(BenchRecord::new(&report, None, Part::Part1, Some("old".to_string())).with_baseline("main"));
+        history.record(BenchRecord::new(&report, None, Part::Part2, Some("part2".to_string())));
+        for _ in 0..MAX_RUNS + 5 {
+            history.record(BenchRecord::new(&report, None, Part::Part1, None));
+        }
+
+        assert_eq!(history.runs().len(), MAX_RUNS + 2);
+        assert_eq!(history.baseline("main", None, Part::Part1).unwrap().revision(), Some("old"));
+        assert_eq!(history.previous(None, Part::Part2).unwrap().revision(), Some("part2"));
+
+        // Only the latest run of a baseline is kept
+        history.record(BenchRecord::new(&report, None, Part::Part1, Some("new".to_string())).with_baseline("main"));
+        assert_eq!(history.runs().len(), MAX_RUNS + 1);
+        assert_eq!(history.baseline("main", None, Part::Part1).unwrap().revision(), Some("new"));
+    }
+}
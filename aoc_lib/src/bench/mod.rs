@@ -0,0 +1,147 @@
+use std::time::{Duration, Instant};
+use anyhow::Result;
+
+mod history;
+mod stats;
+
+pub use history::{git_revision, BenchComparison, BenchHistory, BenchRecord, ChangeVerdict, PhaseChange, PhaseStats};
+pub use stats::{BenchReport, PhaseReport};
+
+/// Configures how long and how often a solution is benchmarked.
+#[derive(Debug, Clone)]
+pub struct BenchConfig {
+    warmup: Duration,
+    budget: Duration,
+    min_samples: u32,
+    max_samples: u32,
+}
+
+impl BenchConfig {
+    /// The time spent running the solution before any samples are collected.
+    pub fn with_warmup(mut self, warmup: Duration) -> Self {
+        self.warmup = warmup;
+        self
+    }
+
+    /// The time spent collecting samples, the number of samples adapts to the runtime of the
+    /// solution but stays between the minimum and maximum number of samples.
+    pub fn with_budget(mut self, budget: Duration) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// The minimum number of samples to collect, even if this exceeds the time budget.
+    pub fn with_min_samples(mut self, min_samples: u32) -> Self {
+        self.min_samples = min_samples.max(1);
+        self.max_samples = self.max_samples.max(self.min_samples);
+        self
+    }
+
+    /// The maximum number of samples to collect, even if the time budget is not used up.
+    pub fn with_max_samples(mut self, max_samples: u32) -> Self {
+        self.max_samples = max_samples.max(1);
+        self.min_samples = self.min_samples.min(self.max_samples);
+        self
+    }
+
+    pub fn warmup(&self) -> Duration {
+        self.warmup
+    }
+
+    pub fn budget(&self) -> Duration {
+        self.budget
+    }
+
+    pub fn min_samples(&self) -> u32 {
+        self.min_samples
+    }
+
+    pub fn max_samples(&self) -> u32 {
+        self.max_samples
+    }
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(500),
+            budget: Duration::from_secs(3),
+            min_samples: 10,
+            max_samples: 100_000,
+        }
+    }
+}
+
+/// Benchmark a function, which runs a single iteration and returns the time each of its phases
+/// took.
+///
+/// Letting the function measure itself allows it to exclude any setup from the sample.
+pub(crate) fn run<F, const N: usize>(config: &BenchConfig, mut sample: F) -> Result<[BenchReport; N]>
+where
+    F: FnMut() -> Result<[Duration; N]>,
+{
+    let warmup_start = Instant::now();
+    loop {
+        sample()?;
+        if warmup_start.elapsed() >= config.warmup {
+            break;
+        }
+    }
+
+    let mut samples: [Vec<Duration>; N] = std::array::from_fn(|_| Vec::new());
+    let start = Instant::now();
+    let mut collected = 0;
+    while collected < config.max_samples
+        && (collected < config.min_samples || start.elapsed() < config.budget)
+    {
+        for (phase, time) in samples.iter_mut().zip(sample()?) {
+            phase.push(time);
+        }
+        collected += 1;
+    }
+
+    Ok(samples.map(BenchReport::from_samples))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_samples() {
+        let config = BenchConfig::default()
+            .with_warmup(Duration::ZERO)
+            .with_budget(Duration::ZERO)
+            .with_min_samples(25);
+
+        let mut calls = 0;
+        let [report] = run(&config, || {
+            calls += 1;
+            Ok([Duration::from_micros(calls)])
+        }).unwrap();
+
+        // One warmup iteration, then exactly the minimum number of samples
+        assert_eq!(calls, 26);
+        assert_eq!(report.samples().len(), 25);
+        assert_eq!(report.min(), Duration::from_micros(2));
+    }
+
+    #[test]
+    fn test_max_samples() {
+        let config = BenchConfig::default()
+            .with_warmup(Duration::ZERO)
+            .with_budget(Duration::from_secs(60))
+            .with_max_samples(5);
+
+        let [parse, solve] = run(&config, || Ok([Duration::from_micros(1), Duration::from_micros(2)])).unwrap();
+        assert_eq!(parse.samples().len(), 5);
+        assert_eq!(solve.samples().len(), 5);
+        assert_eq!(solve.min(), Duration::from_micros(2));
+    }
+
+    #[test]
+    fn test_error_aborts() {
+        let config = BenchConfig::default();
+        assert!(run::<_, 1>(&config, || anyhow::bail!("Solution failed")).is_err());
+    }
+}
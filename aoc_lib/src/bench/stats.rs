@@ -0,0 +1,219 @@
+use std::fmt;
+use std::time::Duration;
+
+use crate::util::duration::DisplayDuration;
+
+/// The statistics of the samples collected by a benchmark.
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    samples: Vec<Duration>,
+    mean: Duration,
+    std_dev: Duration,
+    mild_outliers: usize,
+    severe_outliers: usize,
+}
+
+impl BenchReport {
+    /// Calculate the statistics of the samples.
+    ///
+    /// # Panics
+    /// This function panics if there are no samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "A benchmark needs at least one sample");
+        samples.sort_unstable();
+
+        let secs = samples.iter()
+            .map(Duration::as_secs_f64)
+            .collect::<Vec<_>>();
+        let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+        let variance = if secs.len() > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (secs.len() - 1) as f64
+        } else {
+            0.0
+        };
+
+        // Tukey's fences, samples beyond 1.5 IQR are mild and beyond 3 IQR severe outliers
+        let q1 = quantile(&secs, 0.25);
+        let q3 = quantile(&secs, 0.75);
+        let iqr = q3 - q1;
+        let (mut mild_outliers, mut severe_outliers) = (0, 0);
+        for &s in &secs {
+            if s < q1 - 3.0 * iqr || s > q3 + 3.0 * iqr {
+                severe_outliers += 1;
+            } else if s < q1 - 1.5 * iqr || s > q3 + 1.5 * iqr {
+                mild_outliers += 1;
+            }
+        }
+
+        Self {
+            samples,
+            mean: Duration::from_secs_f64(mean),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+            mild_outliers,
+            severe_outliers,
+        }
+    }
+
+    /// All samples, sorted from fastest to slowest.
+    pub fn samples(&self) -> &[Duration] {
+        &self.samples
+    }
+
+    pub fn mean(&self) -> Duration {
+        self.mean
+    }
+
+    /// The sample standard deviation.
+    pub fn std_dev(&self) -> Duration {
+        self.std_dev
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        self.percentile(0.5)
+    }
+
+    pub fn p95(&self) -> Duration {
+        self.percentile(0.95)
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+
+    /// The sample at the given percentile (0.0..=1.0), using the nearest-rank method.
+    pub fn percentile(&self, p: f64) -> Duration {
+        let rank = (p.clamp(0.0, 1.0) * self.samples.len() as f64).ceil() as usize;
+        self.samples[rank.saturating_sub(1)]
+    }
+
+    /// Samples between 1.5 and 3 interquartile ranges outside of the quartiles.
+    pub fn mild_outliers(&self) -> usize {
+        self.mild_outliers
+    }
+
+    /// Samples more than 3 interquartile ranges outside of the quartiles.
+    pub fn severe_outliers(&self) -> usize {
+        self.severe_outliers
+    }
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "mean {} ± {} (min {}, median {}, p95 {}, max {}) over {} samples",
+            DisplayDuration(self.mean),
+            DisplayDuration(self.std_dev),
+            DisplayDuration(self.min()),
+            DisplayDuration(self.median()),
+            DisplayDuration(self.p95()),
+            DisplayDuration(self.max()),
+            self.samples.len(),
+        )?;
+
+        let outliers = self.mild_outliers + self.severe_outliers;
+        if outliers > 0 {
+            write!(f, ", {} outliers ({} severe)", outliers, self.severe_outliers)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The benchmark results of a solution part, with parsing and solving measured separately.
+#[derive(Debug, Clone)]
+pub struct PhaseReport {
+    parse: BenchReport,
+    solve: BenchReport,
+    shared_parse: bool,
+}
+
+impl PhaseReport {
+    pub fn new(parse: BenchReport, solve: BenchReport) -> Self {
+        Self {
+            parse,
+            solve,
+            shared_parse: false,
+        }
+    }
+
+    /// Mark the parsing as shared with the other part of the day.
+    pub fn with_shared_parse(mut self) -> Self {
+        self.shared_parse = true;
+        self
+    }
+
+    pub fn parse(&self) -> &BenchReport {
+        &self.parse
+    }
+
+    pub fn solve(&self) -> &BenchReport {
+        &self.solve
+    }
+
+    /// Whether the input was parsed once for both parts of the day.
+    pub fn shared_parse(&self) -> bool {
+        self.shared_parse
+    }
+}
+
+impl fmt::Display for PhaseReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let shared = if self.shared_parse { " (shared)" } else { "" };
+        writeln!(f, "  Parse{}: {}", shared, self.parse)?;
+        write!(f, "  Solve: {}", self.solve)
+    }
+}
+
+/// Linearly interpolated quantile of sorted values.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let lower = pos.floor() as usize;
+    let upper = pos.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (pos - lower as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn test_percentiles() {
+        let report = BenchReport::from_samples(millis(&[5, 1, 4, 2, 3, 10, 9, 8, 7, 6]));
+        assert_eq!(report.min(), Duration::from_millis(1));
+        assert_eq!(report.median(), Duration::from_millis(5));
+        assert_eq!(report.p95(), Duration::from_millis(10));
+        assert_eq!(report.max(), Duration::from_millis(10));
+        assert_eq!(report.mean(), Duration::from_micros(5500));
+    }
+
+    #[test]
+    fn test_std_dev() {
+        let report = BenchReport::from_samples(millis(&[2, 4, 4, 4, 5, 5, 7, 9]));
+        let expected = (32.0f64 / 7.0).sqrt() / 1000.0;
+        assert!((report.std_dev().as_secs_f64() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_outliers() {
+        let report = BenchReport::from_samples(millis(&[10, 10, 11, 11, 12, 12, 13, 13, 17, 40]));
+        assert_eq!(report.mild_outliers(), 1);
+        assert_eq!(report.severe_outliers(), 1);
+    }
+
+    #[test]
+    fn test_single_sample() {
+        let report = BenchReport::from_samples(millis(&[3]));
+        assert_eq!(report.median(), Duration::from_millis(3));
+        assert_eq!(report.std_dev(), Duration::ZERO);
+        assert_eq!(report.mild_outliers() + report.severe_outliers(), 0);
+    }
+}
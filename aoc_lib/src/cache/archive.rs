@@ -0,0 +1,143 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use anyhow::{bail, Context, Result};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use super::Cache;
+
+/// The result of importing an archive.
+#[derive(Debug, Clone, Default)]
+pub struct ImportSummary {
+    /// The inputs which were written to the cache, as year and day.
+    pub imported: Vec<(u16, u8)>,
+    /// The inputs which were already cached and left untouched.
+    pub skipped: Vec<(u16, u8)>,
+}
+
+/// The name of an input inside an archive, independent of the layout of the cache.
+fn archive_name(year: u16, day: u8) -> String {
+    format!("{}/day{:0>2}-{}", year, day, Cache::INPUT)
+}
+
+fn parse_archive_name(name: &str) -> Option<(u16, u8)> {
+    let (year, file) = name.trim_start_matches("./").split_once('/')?;
+    let day = file.strip_prefix("day")?.strip_suffix(&format!("-{}", Cache::INPUT))?;
+
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+impl Cache {
+    /// Write the cached inputs into a gzipped tarball, optionally only of the given year and day.
+    ///
+    /// Returns the exported inputs, as year and day.
+    pub fn export(&self, path: &Path, year: Option<u16>, day: Option<u8>) -> Result<Vec<(u16, u8)>> {
+        let entries = self.entries(year, day)?;
+        if entries.is_empty() {
+            bail!("There are no cached inputs to export");
+        }
+
+        let file = File::create(path)
+            .with_context(|| format!("Failed to create {}", path.display()))?;
+        let mut archive = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+        for entry in &entries {
+            archive.append_path_with_name(&entry.path, archive_name(entry.year, entry.day))
+                .with_context(|| format!("Failed to add {} to the archive", entry.path.display()))?;
+        }
+
+        archive.into_inner()
+            .and_then(|encoder| encoder.finish())
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        Ok(entries.iter().map(|entry| (entry.year, entry.day)).collect())
+    }
+
+    /// Read the inputs of a tarball created by [`Cache::export`] into the cache.
+    ///
+    /// Only the inputs of the given year and day are imported, if any. Inputs which are already
+    /// cached are only replaced if `overwrite` is set.
+    pub fn import(&self, path: &Path, year: Option<u16>, day: Option<u8>, overwrite: bool) -> Result<ImportSummary> {
+        let file = File::open(path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        let mut archive = tar::Archive::new(GzDecoder::new(file));
+
+        let mut summary = ImportSummary::default();
+        let entries = archive.entries()
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        for entry in entries {
+            let mut entry = entry.with_context(|| format!("Failed to read {}", path.display()))?;
+            let name = entry.path()
+                .context("The archive contains an invalid path")?
+                .to_string_lossy()
+                .into_owned();
+
+            let Some((input_year, input_day)) = parse_archive_name(&name) else {
+                continue; // Not an input, e.g. a directory entry
+            };
+            if year.is_some_and(|year| year != input_year) || day.is_some_and(|day| day != input_day) {
+                continue;
+            }
+            let (year, day) = (input_year, input_day);
+
+            if !overwrite && self.read_input(year, day)?.is_some() {
+                summary.skipped.push((year, day));
+                continue;
+            }
+
+            let mut text = String::new();
+            entry.read_to_string(&mut text)
+                .with_context(|| format!("Failed to read {} from the archive", name))?;
+            self.write_input(year, day, &text)
+                .with_context(|| format!("Failed to import the input of {} day {}", year, day))?;
+            summary.imported.push((year, day));
+        }
+
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use super::*;
+    use crate::cache::CacheLayout;
+
+    #[test]
+    fn test_parse_archive_name() {
+        assert_eq!(parse_archive_name("2024/day05-input.txt"), Some((2024, 5)));
+        assert_eq!(parse_archive_name("./2023/day25-input.txt"), Some((2023, 25)));
+        assert_eq!(parse_archive_name("2024/day05-answers.json"), None);
+        assert_eq!(parse_archive_name("2024/"), None);
+    }
+
+    #[test]
+    fn test_export_import() {
+        let dir = std::env::temp_dir().join(format!("aoc-archive-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let source = Cache::new(dir.join("source"));
+        source.write_input(2024, 1, "1 2\n").unwrap();
+        source.write_input(2024, 7, "3 4\n").unwrap();
+
+        let archive = dir.join("inputs.tar.gz");
+        assert_eq!(source.export(&archive, None, None).unwrap(), [(2024, 1), (2024, 7)]);
+
+        let target = Cache::new(dir.join("target")).with_layout(CacheLayout::Nested);
+        target.write_input(2024, 7, "old\n").unwrap();
+
+        let summary = target.import(&archive, Some(2024), Some(7), true).unwrap();
+        assert_eq!(summary.imported, [(2024, 7)]);
+        assert_eq!(target.read_input(2024, 1).unwrap(), None);
+        target.write_input(2024, 7, "old\n").unwrap();
+
+        let summary = target.import(&archive, Some(2024), None, false).unwrap();
+        assert_eq!(summary.imported, [(2024, 1)]);
+        assert_eq!(summary.skipped, [(2024, 7)]);
+        assert_eq!(target.read_input(2024, 1).unwrap().as_deref(), Some("1 2\n"));
+        assert_eq!(target.read_input(2024, 7).unwrap().as_deref(), Some("old\n"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,19 @@
+use std::fmt;
+
+/// An account name which can't be used as the name of its cache directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidAccountName {
+    pub name: String,
+}
+
+impl fmt::Display for InvalidAccountName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The account name {:?} is not allowed, it must not be empty, contain path separators or start with a dot",
+            self.name
+        )
+    }
+}
+
+impl std::error::Error for InvalidAccountName {}
@@ -0,0 +1,405 @@
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::schedule;
+
+mod archive;
+mod error;
+
+pub use archive::ImportSummary;
+pub use error::InvalidAccountName;
+
+/// How the files of a day are laid out inside the cache root.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheLayout {
+    /// `{year}/day{NN}-{file}`
+    #[default]
+    Flat,
+    /// `{year}/day{NN}/{file}`
+    Nested,
+}
+
+/// The on-disk cache of puzzle inputs, answer ledgers and benchmark histories.
+#[derive(Debug, Clone)]
+pub struct Cache {
+    root: PathBuf,
+    /// The root of the shared cache, the same as `root` unless this is the cache of an account.
+    shared_root: PathBuf,
+    layout: CacheLayout,
+    account: Option<String>,
+}
+
+/// A cached puzzle input.
+#[derive(Debug, Clone)]
+pub struct CacheEntry {
+    pub year: u16,
+    pub day: u8,
+    pub path: PathBuf,
+    /// The size of the input in bytes.
+    pub size: u64,
+}
+
+/// Something which is wrong with a cached input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheProblem {
+    Unreadable(String),
+    Empty,
+    /// The input looks like an html page, e.g. an error page of the server.
+    Html,
+    MissingTrailingNewline,
+}
+
+impl Cache {
+    pub const INPUT: &'static str = "input.txt";
+    /// The html page of the puzzle description.
+    pub const PUZZLE: &'static str = "puzzle.html";
+    /// The directory below the root which holds the caches of named accounts.
+    const ACCOUNTS: &'static str = "accounts";
+    /// The cache of earlier versions, in the working directory.
+    pub const LEGACY_ROOT: &'static str = ".cache";
+
+    /// The root of the cache if none is configured: `aoc` in the user's cache directory.
+    ///
+    /// A cache of an earlier version in the working directory is used as long as it exists, so
+    /// its inputs aren't fetched again.
+    pub fn default_root() -> PathBuf {
+        default_root_of(Path::new(Self::LEGACY_ROOT), dirs::cache_dir())
+    }
+
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        let root = root.into();
+        Self {
+            shared_root: root.clone(),
+            root,
+            layout: CacheLayout::default(),
+            account: None,
+        }
+    }
+
+    /// The cache of a named account, every account has its own inputs, answers and benchmarks.
+    ///
+    /// Fails if the name could reach outside of the directory of the accounts.
+    pub fn for_account(&self, name: &str) -> Result<Self, InvalidAccountName> {
+        if !is_valid_account(name) {
+            return Err(InvalidAccountName { name: name.to_string() });
+        }
+
+        Ok(Self {
+            root: self.root.join(Self::ACCOUNTS).join(name),
+            shared_root: self.shared_root.clone(),
+            layout: self.layout,
+            account: Some(name.to_string()),
+        })
+    }
+
+    /// The names of the accounts which have a cache below this one, sorted.
+    pub fn accounts(&self) -> Result<Vec<String>> {
+        let dir = self.root.join(Self::ACCOUNTS);
+        let dirs = match fs::read_dir(&dir) {
+            Ok(dirs) => dirs,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => {
+                return Err(anyhow::Error::from(err)
+                    .context(format!("Failed to read the account caches at {}", dir.display())))
+            }
+        };
+
+        let mut accounts = Vec::new();
+        for entry in dirs {
+            let entry = entry.with_context(|| format!("Failed to read the account caches at {}", dir.display()))?;
+            if entry.path().is_dir() {
+                if let Some(name) = entry.file_name().to_str().filter(|name| is_valid_account(name)) {
+                    accounts.push(name.to_string());
+                }
+            }
+        }
+        accounts.sort_unstable();
+
+        Ok(accounts)
+    }
+
+    pub fn with_layout(mut self, layout: CacheLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The root of the shared cache, for what belongs to the machine rather than an account, e.g.
+    /// the time of the last request to the server.
+    pub fn shared_root(&self) -> &Path {
+        &self.shared_root
+    }
+
+    pub fn layout(&self) -> CacheLayout {
+        self.layout
+    }
+
+    /// The account the cache belongs to, `None` for the shared cache.
+    pub fn account(&self) -> Option<&str> {
+        self.account.as_deref()
+    }
+
+    /// The path of a file belonging to the given day.
+    pub fn path(&self, year: u16, day: u8, file: &str) -> PathBuf {
+        let year_dir = self.root.join(year.to_string());
+        match self.layout {
+            CacheLayout::Flat => year_dir.join(format!("day{:0>2}-{}", day, file)),
+            CacheLayout::Nested => year_dir.join(format!("day{:0>2}", day)).join(file),
+        }
+    }
+
+    /// Read the cached input of the given day, `None` if it is not cached.
+    pub fn read_input(&self, year: u16, day: u8) -> Result<Option<String>> {
+        self.read_text(year, day, Self::INPUT)
+    }
+
+    pub fn write_input(&self, year: u16, day: u8, text: &str) -> Result<(), io::Error> {
+        self.write_text(year, day, Self::INPUT, text)
+    }
+
+    /// Read a cached file of the given day, `None` if it is not cached.
+    pub fn read_text(&self, year: u16, day: u8, name: &str) -> Result<Option<String>> {
+        let path = self.path(year, day, name);
+        let mut file = match File::open(&path) {
+            Ok(file) => file,
+            Err(err) => {
+                return if err.kind() == io::ErrorKind::NotFound {
+                    Ok(None) // File not found
+                } else {
+                    Err(anyhow::Error::from(err)
+                        .context(format!("Something went wrong while trying to open the cached {}", name)))
+                }
+            }
+        };
+
+        let size = file.metadata().map(|m| m.len() as usize).ok();
+        let mut res = String::new();
+        res.try_reserve_exact(size.unwrap_or(0))
+            .with_context(|| format!("Failed to read the cached {}", name))?;
+        file.read_to_string(&mut res)
+            .with_context(|| format!("Failed to read the cached {}", name))?;
+
+        Ok(Some(res))
+    }
+
+    pub fn write_text(&self, year: u16, day: u8, name: &str, text: &str) -> Result<(), io::Error> {
+        let path = self.path(year, day, name);
+        fs::create_dir_all(path.parent().expect("Path should have parent"))?;
+        fs::write(&path, text)
+    }
+
+    /// The path of a file belonging to the whole year, e.g. a leaderboard.
+    pub fn year_path(&self, year: u16, file: &str) -> PathBuf {
+        self.root.join(year.to_string()).join(file)
+    }
+
+    /// Read a json file of the given day, the default value is returned if there is none yet.
+    pub(crate) fn read_json<T: DeserializeOwned + Default>(&self, year: u16, day: u8, file: &str, what: &str) -> Result<T> {
+        Self::read_json_at(&self.path(year, day, file), what)
+            .map(Option::unwrap_or_default)
+    }
+
+    pub(crate) fn write_json<T: Serialize>(&self, year: u16, day: u8, file: &str, what: &str, value: &T) -> Result<()> {
+        Self::write_json_at(&self.path(year, day, file), what, value)
+    }
+
+    /// Read a json file, `None` if there is none.
+    pub(crate) fn read_json_at<T: DeserializeOwned>(path: &Path, what: &str) -> Result<Option<T>> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => {
+                return Err(anyhow::Error::from(err)
+                    .context(format!("Something went wrong while trying to read the {}", what)))
+            }
+        };
+
+        serde_json::from_str(&text)
+            .map(Some)
+            .with_context(|| format!("The {} at {} is malformed", what, path.display()))
+    }
+
+    pub(crate) fn write_json_at<T: Serialize>(path: &Path, what: &str, value: &T) -> Result<()> {
+        let text = serde_json::to_string_pretty(value)
+            .with_context(|| format!("Failed to serialize the {}", what))?;
+
+        fs::create_dir_all(path.parent().expect("Path should have parent"))
+            .and_then(|_| fs::write(path, text))
+            .with_context(|| format!("Failed to save the {}", what))
+    }
+
+    /// All cached inputs, optionally only of the given year and day, sorted by year and day.
+    pub fn entries(&self, year: Option<u16>, day: Option<u8>) -> Result<Vec<CacheEntry>> {
+        let dirs = match fs::read_dir(&self.root) {
+            Ok(dirs) => dirs,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => {
+                return Err(anyhow::Error::from(err)
+                    .context(format!("Failed to read the cache at {}", self.root.display())))
+            }
+        };
+
+        let mut years = Vec::new();
+        for dir in dirs {
+            let dir = dir.with_context(|| format!("Failed to read the cache at {}", self.root.display()))?;
+            if let Some(cached_year) = dir.file_name().to_str().and_then(|name| name.parse::<u16>().ok()) {
+                years.push(cached_year);
+            }
+        }
+        years.sort_unstable();
+
+        let mut entries = Vec::new();
+        for cached_year in years.into_iter().filter(|&cached_year| year.is_none_or(|year| year == cached_year)) {
+            for cached_day in (1..=schedule::last_day(cached_year)).filter(|&cached_day| day.is_none_or(|day| day == cached_day)) {
+                let path = self.path(cached_year, cached_day, Self::INPUT);
+                if let Ok(metadata) = fs::metadata(&path) {
+                    entries.push(CacheEntry {
+                        year: cached_year,
+                        day: cached_day,
+                        path,
+                        size: metadata.len(),
+                    });
+                }
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Check the cached inputs for anything which doesn't look like a puzzle input.
+    pub fn verify(&self, year: Option<u16>, day: Option<u8>) -> Result<Vec<(CacheEntry, CacheProblem)>> {
+        let problems = self.entries(year, day)?
+            .into_iter()
+            .filter_map(|entry| {
+                let problem = match fs::read_to_string(&entry.path) {
+                    Err(err) => Some(CacheProblem::Unreadable(err.to_string())),
+                    Ok(text) if text.trim().is_empty() => Some(CacheProblem::Empty),
+                    Ok(text) if text.trim_start().starts_with('<') => Some(CacheProblem::Html),
+                    Ok(text) if !text.ends_with('\n') => Some(CacheProblem::MissingTrailingNewline),
+                    Ok(_) => None,
+                };
+
+                problem.map(|problem| (entry, problem))
+            })
+            .collect();
+
+        Ok(problems)
+    }
+
+    /// Remove the cached inputs, optionally only of the given year and day.
+    ///
+    /// Answer ledgers and benchmark histories are kept. Returns the removed entries.
+    pub fn purge(&self, year: Option<u16>, day: Option<u8>) -> Result<Vec<CacheEntry>> {
+        let entries = self.entries(year, day)?;
+        for entry in &entries {
+            fs::remove_file(&entry.path)
+                .with_context(|| format!("Failed to remove {}", entry.path.display()))?;
+        }
+
+        Ok(entries)
+    }
+}
+
+fn default_root_of(legacy: &Path, user_cache_dir: Option<PathBuf>) -> PathBuf {
+    match user_cache_dir {
+        Some(dir) if !legacy.is_dir() => dir.join("aoc"),
+        _ => legacy.to_path_buf(),
+    }
+}
+
+/// Whether the account name is a single directory name below the accounts, not `..` or hidden.
+fn is_valid_account(name: &str) -> bool {
+    !name.is_empty() && !name.starts_with('.') && !name.contains(['/', '\\'])
+}
+
+impl std::fmt::Display for CacheProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unreadable(err) => write!(f, "The input can't be read: {}", err),
+            Self::Empty => write!(f, "The input is empty"),
+            Self::Html => write!(f, "The input looks like an html page"),
+            Self::MissingTrailingNewline => write!(f, "The input doesn't end with a newline, it might be truncated"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache(name: &str) -> Cache {
+        let root = std::env::temp_dir().join(format!("aoc-cache-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        Cache::new(root)
+    }
+
+    #[test]
+    fn test_layouts() {
+        let cache = Cache::new("cache");
+        assert_eq!(cache.path(2024, 3, Cache::INPUT), Path::new("cache/2024/day03-input.txt"));
+
+        let cache = cache.with_layout(CacheLayout::Nested);
+        assert_eq!(cache.path(2024, 3, Cache::INPUT), Path::new("cache/2024/day03/input.txt"));
+    }
+
+    #[test]
+    fn test_entries_verify_purge() {
+        let cache = temp_cache("entries").with_layout(CacheLayout::Nested);
+        cache.write_input(2024, 2, "1 2\n").unwrap();
+        cache.write_input(2024, 1, "").unwrap();
+        cache.write_input(2023, 5, "<html>").unwrap();
+
+        let entries = cache.entries(None, None).unwrap();
+        let days = entries.iter().map(|entry| (entry.year, entry.day)).collect::<Vec<_>>();
+        assert_eq!(days, [(2023, 5), (2024, 1), (2024, 2)]);
+
+        let problems = cache.verify(Some(2024), None).unwrap();
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].1, CacheProblem::Empty);
+
+        assert_eq!(cache.purge(Some(2024), Some(1)).unwrap().len(), 1);
+        assert_eq!(cache.entries(None, None).unwrap().len(), 2);
+
+        fs::remove_dir_all(cache.root()).unwrap();
+    }
+
+    #[test]
+    fn test_default_root() {
+        let cache = temp_cache("default-root");
+        let legacy = cache.root().join(Cache::LEGACY_ROOT);
+        let user_cache_dir = cache.root().join("user");
+
+        assert_eq!(default_root_of(&legacy, Some(user_cache_dir.clone())), user_cache_dir.join("aoc"));
+        assert_eq!(default_root_of(&legacy, None), legacy);
+        fs::create_dir_all(&legacy).unwrap();
+        assert_eq!(default_root_of(&legacy, Some(user_cache_dir)), legacy);
+
+        fs::remove_dir_all(cache.root()).unwrap();
+    }
+
+    #[test]
+    fn test_accounts() {
+        let cache = temp_cache("accounts");
+        cache.write_input(2024, 1, "shared\n").unwrap();
+        cache.for_account("bob").unwrap().write_input(2024, 1, "bob\n").unwrap();
+        cache.for_account("alice").unwrap().write_input(2024, 1, "alice\n").unwrap();
+
+        assert_eq!(cache.accounts().unwrap(), ["alice", "bob"]);
+        assert_eq!(cache.for_account("bob").unwrap().shared_root(), cache.root());
+        assert_eq!(cache.for_account("bob").unwrap().read_input(2024, 1).unwrap().as_deref(), Some("bob\n"));
+        for name in ["", "..", ".hidden", "a/b", "a\\b", "../bob"] {
+            assert_eq!(cache.for_account(name).unwrap_err(), InvalidAccountName { name: name.to_string() });
+        }
+        assert_eq!(cache.entries(None, None).unwrap().len(), 1);
+
+        fs::remove_dir_all(cache.root()).unwrap();
+    }
+}
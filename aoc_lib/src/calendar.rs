@@ -0,0 +1,101 @@
+use std::collections::BTreeMap;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::description::html;
+use crate::Part;
+
+/// How long a fetched calendar is used before it is fetched again.
+pub const CALENDAR_TTL: Duration = Duration::minutes(15);
+
+/// The stars the account earned per day, as shown on the calendar page of the year.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Calendar {
+    /// The stars per day, days without stars are left out.
+    stars: BTreeMap<u8, u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fetched_at: Option<DateTime<Utc>>,
+}
+
+impl Calendar {
+    /// Read the stars from the html of the calendar page.
+    ///
+    /// Every day is a link with a class like `calendar-day3`, and `calendar-complete` or
+    /// `calendar-verycomplete` for one or two stars.
+    pub fn parse(page: &str) -> Self {
+        let root = html::parse(page);
+
+        let stars = root.find_all("a")
+            .into_iter()
+            .filter_map(|link| {
+                let classes = link.attr("class")?;
+                let day = classes.split_whitespace()
+                    .find_map(|class| class.strip_prefix("calendar-day")?.parse::<u8>().ok())?;
+
+                let stars = if link.has_class("calendar-verycomplete") {
+                    2
+                } else if link.has_class("calendar-complete") {
+                    1
+                } else {
+                    0
+                };
+
+                Some((day, stars))
+            })
+            .filter(|&(_, stars)| stars > 0)
+            .collect();
+
+        Self { stars, fetched_at: None }
+    }
+
+    /// The number of stars earned on the day, from 0 to 2.
+    pub fn stars(&self, day: u8) -> u8 {
+        self.stars.get(&day).copied().unwrap_or(0)
+    }
+
+    /// The number of stars earned in the whole year.
+    pub fn total(&self) -> u32 {
+        self.stars.values().map(|&stars| stars as u32).sum()
+    }
+
+    /// Record a star which was earned since the calendar was fetched.
+    pub fn record_star(&mut self, day: u8, part: Part) {
+        let stars = self.stars.entry(day).or_default();
+        *stars = (*stars).max(part.level());
+    }
+
+    pub fn fetched_at(&self) -> Option<DateTime<Utc>> {
+        self.fetched_at
+    }
+
+    pub(crate) fn with_fetched_at(mut self, fetched_at: DateTime<Utc>) -> Self {
+        self.fetched_at = Some(fetched_at);
+        self
+    }
+
+    /// Whether the calendar was fetched recently enough that it shouldn't be fetched again.
+    pub fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        self.fetched_at.is_some_and(|fetched_at| now - fetched_at < CALENDAR_TTL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let page = r#"<pre class="calendar">
+<a aria-label="Day 1, two stars" href="/2024/day/1" class="calendar-day1 calendar-verycomplete">x</a>
+<a aria-label="Day 2, one star" href="/2024/day/2" class="calendar-day2 calendar-complete">x</a>
+<a aria-label="Day 3" href="/2024/day/3" class="calendar-day3">x</a>
+<span class="calendar-day4">x</span>
+</pre>"#;
+
+        let calendar = Calendar::parse(page);
+        assert_eq!(calendar.stars(1), 2);
+        assert_eq!(calendar.stars(2), 1);
+        assert_eq!(calendar.stars(3), 0);
+        assert_eq!(calendar.total(), 3);
+    }
+}
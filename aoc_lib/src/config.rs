@@ -0,0 +1,107 @@
+use std::env;
+use std::path::{Path, PathBuf};
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+
+use crate::cache::{Cache, CacheLayout};
+use crate::credentials::{Credentials, Session};
+use crate::io;
+
+/// The settings of the config file, environment variables take precedence over them.
+///
+/// The file is read from `AOC_CONFIG`, or `aoc/config.toml` in the user's config directory.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    cache_dir: Option<PathBuf>,
+    cache_layout: Option<CacheLayout>,
+    /// The id of the private leaderboard to show by default.
+    leaderboard: Option<u64>,
+    #[serde(flatten)]
+    credentials: Credentials,
+}
+
+impl Config {
+    /// The path of the config file, `AOC_CONFIG` if it is set.
+    pub fn path() -> Option<PathBuf> {
+        env::var_os("AOC_CONFIG")
+            .map(PathBuf::from)
+            .or_else(|| dirs::config_dir().map(|dir| dir.join("aoc").join("config.toml")))
+    }
+
+    /// Load the config file, there being none is the same as an empty config unless `AOC_CONFIG`
+    /// points to it.
+    pub fn load() -> Result<Self> {
+        match Self::path() {
+            Some(path) if path.exists() || env::var_os("AOC_CONFIG").is_some() => Self::load_from(&path),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read the config file {}", path.display()))?;
+
+        toml::from_str(&text)
+            .with_context(|| format!("The config file {} is malformed", path.display()))
+    }
+
+    pub fn leaderboard(&self) -> Option<u64> {
+        self.leaderboard
+    }
+
+    pub fn credentials(&self) -> &Credentials {
+        &self.credentials
+    }
+
+    /// The session of the given account, or the default session.
+    pub fn session(&self, account: Option<&str>) -> Result<Session> {
+        self.credentials.session(account)
+    }
+
+    /// The cache, located by `AOC_CACHE_DIR`, the config file, or [`Cache::default_root`], in
+    /// that order.
+    pub fn cache(&self) -> Result<Cache> {
+        let root = env::var_os("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .or_else(|| self.cache_dir.clone())
+            .unwrap_or_else(|| {
+                let root = Cache::default_root();
+                if root == Path::new(Cache::LEGACY_ROOT) {
+                    if let Some(dir) = dirs::cache_dir() {
+                        io::print_warning(format_args!(
+                            "Using the cache of an earlier version in {}, move it to {} or set cache_dir in the config file",
+                            root.display(), dir.join("aoc").display()
+                        ));
+                    }
+                }
+                root
+            });
+
+        let layout = match env::var("AOC_CACHE_LAYOUT") {
+            Ok(layout) => match layout.to_lowercase().as_str() {
+                "flat" => CacheLayout::Flat,
+                "nested" => CacheLayout::Nested,
+                _ => bail!("Unknown cache layout \"{}\" in AOC_CACHE_LAYOUT, expected \"flat\" or \"nested\"", layout),
+            },
+            Err(_) => self.cache_layout.unwrap_or_default(),
+        };
+
+        Ok(Cache::new(root).with_layout(layout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config: Config = toml::from_str("cache_dir = \"/tmp/aoc\"\ncache_layout = \"nested\"\n").unwrap();
+        assert_eq!(config.cache_dir.as_deref(), Some(Path::new("/tmp/aoc")));
+        assert_eq!(config.cache_layout, Some(CacheLayout::Nested));
+
+        let config: Config = toml::from_str("").unwrap();
+        assert!(config.cache_dir.is_none());
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::{env, fmt, fs};
+use anyhow::{bail, Context, Result};
+use reqwest::header::HeaderValue;
+use serde::Deserialize;
+
+/// A validated aoc session cookie.
+#[derive(Clone)]
+pub struct Session {
+    value: String,
+}
+
+/// Why a session cookie was rejected before it was ever sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidSession {
+    Empty,
+    /// The cookie contains characters other than hex digits.
+    NotHex,
+    /// The cookie is too short to be a session, it was probably copied partially.
+    TooShort { len: usize },
+}
+
+impl Session {
+    /// The shortest session cookie which is accepted, real ones are 128 hex digits.
+    const MIN_LEN: usize = 32;
+
+    /// Validate the session cookie, surrounding whitespace and a `session=` prefix are ignored.
+    pub fn parse(text: &str) -> Result<Self, InvalidSession> {
+        let text = text.trim();
+        let value = text.strip_prefix("session=").unwrap_or(text);
+
+        if value.is_empty() {
+            return Err(InvalidSession::Empty);
+        }
+        if !value.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(InvalidSession::NotHex);
+        }
+        if value.len() < Self::MIN_LEN {
+            return Err(InvalidSession::TooShort { len: value.len() });
+        }
+
+        Ok(Self { value: value.to_string() })
+    }
+
+    /// Read the session cookie from a file, e.g. one written by a password manager.
+    pub fn read_from(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read the session file {}", path.display()))?;
+
+        Self::parse(&text)
+            .with_context(|| format!("The session file {} doesn't contain a valid session", path.display()))
+    }
+
+    /// The value of the cookie header, marked as sensitive so it never shows up in logs.
+    pub(crate) fn header(&self) -> HeaderValue {
+        let mut header = HeaderValue::from_str(&format!("session={}", self.value))
+            .expect("A validated session is a valid header value");
+        header.set_sensitive(true);
+        header
+    }
+}
+
+impl fmt::Debug for Session {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Session(..)")
+    }
+}
+
+impl fmt::Display for InvalidSession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "The session cookie is empty"),
+            Self::NotHex => write!(f, "The session cookie may only contain hex digits"),
+            Self::TooShort { len } => write!(f, "The session cookie is only {} characters long, it was probably not copied completely", len),
+        }
+    }
+}
+
+impl std::error::Error for InvalidSession {}
+
+/// A named account of the config file, with either the session itself or a file containing it.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Account {
+    session: Option<String>,
+    session_file: Option<PathBuf>,
+}
+
+impl Account {
+    fn session(&self) -> Result<Option<Session>> {
+        match (&self.session, &self.session_file) {
+            (Some(_), Some(_)) => bail!("Only one of session and session_file may be set"),
+            (Some(session), None) => Ok(Some(Session::parse(session)?)),
+            (None, Some(path)) => Session::read_from(path).map(Some),
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+/// The sessions of the config file.
+///
+/// Without an explicit account the session is looked up in `AOC_SESSION_COOKIE`,
+/// `AOC_SESSION_FILE`, the top-level `session` or `session_file`, and the `default_account`, in
+/// that order.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Credentials {
+    #[serde(flatten)]
+    default: Account,
+    default_account: Option<String>,
+    accounts: BTreeMap<String, Account>,
+}
+
+impl Credentials {
+    /// The names of the configured accounts, sorted.
+    pub fn accounts(&self) -> impl Iterator<Item = &str> {
+        self.accounts.keys().map(String::as_str)
+    }
+
+    /// The account whose session is used, `None` if the session doesn't belong to a named account.
+    pub fn active_account<'a>(&'a self, account: Option<&'a str>) -> Option<&'a str> {
+        if account.is_some() {
+            return account;
+        }
+
+        let unnamed = env::var_os("AOC_SESSION_COOKIE").is_some()
+            || env::var_os("AOC_SESSION_FILE").is_some()
+            || self.default.session.is_some()
+            || self.default.session_file.is_some();
+        if unnamed {
+            return None;
+        }
+
+        self.default_account.as_deref()
+    }
+
+    /// Get the session of the given account, or the default session.
+    pub fn session(&self, account: Option<&str>) -> Result<Session> {
+        if let Some(name) = account {
+            return self.account_session(name);
+        }
+
+        if let Ok(session) = env::var("AOC_SESSION_COOKIE") {
+            return Session::parse(&session)
+                .context("AOC_SESSION_COOKIE doesn't contain a valid session");
+        }
+        if let Some(path) = env::var_os("AOC_SESSION_FILE") {
+            return Session::read_from(Path::new(&path));
+        }
+        if let Some(session) = self.default.session().context("The session in the config file is invalid")? {
+            return Ok(session);
+        }
+        if let Some(name) = &self.default_account {
+            return self.account_session(name);
+        }
+
+        bail!("No session is configured, set AOC_SESSION_COOKIE or AOC_SESSION_FILE, add a session to the config file, or use --offline to only use cached inputs")
+    }
+
+    fn account_session(&self, name: &str) -> Result<Session> {
+        let Some(account) = self.accounts.get(name) else {
+            if self.accounts.is_empty() {
+                bail!("Unknown account \"{}\", no accounts are configured", name);
+            }
+            bail!("Unknown account \"{}\", the configured accounts are: {}", name, self.accounts().collect::<Vec<_>>().join(", "));
+        };
+
+        account.session()
+            .with_context(|| format!("The session of account \"{}\" is invalid", name))?
+            .with_context(|| format!("The account \"{}\" has neither a session nor a session_file", name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_session() {
+        let hex = "0123456789abcdef".repeat(8);
+        assert_eq!(Session::parse(&format!("  session={}\n", hex)).unwrap().value, hex);
+        assert_eq!(Session::parse(" \n").unwrap_err(), InvalidSession::Empty);
+        assert_eq!(Session::parse("session=xyz").unwrap_err(), InvalidSession::NotHex);
+        assert_eq!(Session::parse("abc123").unwrap_err(), InvalidSession::TooShort { len: 6 });
+    }
+
+    #[test]
+    fn test_accounts() {
+        let alice = "a".repeat(128);
+        let credentials: Credentials = toml::from_str(&format!(
+            "default_account = \"alice\"\n[accounts.alice]\nsession = \"{}\"\n[accounts.bob]\n", alice
+        )).unwrap();
+
+        assert_eq!(credentials.accounts().collect::<Vec<_>>(), ["alice", "bob"]);
+        assert_eq!(credentials.session(Some("alice")).unwrap().value, alice);
+        assert_eq!(credentials.active_account(Some("bob")), Some("bob"));
+        assert!(credentials.session(Some("bob")).is_err());
+        assert!(credentials.session(Some("carol")).is_err());
+    }
+}
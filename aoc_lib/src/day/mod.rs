@@ -1,9 +1,14 @@
 use std::fmt;
-use crate::{SolutionPart1, SolutionPart2};
+use anyhow::{bail, Result};
+use crate::bench::{BenchConfig, PhaseReport};
+use crate::puzzle::{ExampleFailure, SolutionOutput};
+use crate::input_source::InputSource;
+use crate::{Part, SolutionPart1, SolutionPart2};
 
 pub mod solved;
 pub mod partial;
 pub mod unsolved;
+pub mod register;
 
 pub use solved::SolvedDay;
 pub use partial::PartialSolvedDay;
@@ -14,12 +19,16 @@ pub enum Day {
     Unsolved(UnsolvedDay),
     Partial(partial::PartialSolvedDayBox),
     Solved(solved::SolvedDayBox),
+    /// The last day of a season, its second star is awarded for finishing every other day, so
+    /// it only has part 1 to solve.
+    Final(partial::PartialSolvedDayBox),
 }
 
 #[derive(Clone)]
 pub enum PartialDay {
     Partial(partial::PartialSolvedDayBox),
     Solved(solved::SolvedDayBox),
+    Final(partial::PartialSolvedDayBox),
 }
 
 impl Day {
@@ -35,11 +44,25 @@ impl Day {
         Self::Solved(SolvedDay::<P>::boxed())
     }
 
+    /// The last day of a season, which is solved with part 1 alone.
+    pub fn final_day<P: SolutionPart1>() -> Self {
+        Self::Final(PartialSolvedDay::<P>::boxed())
+    }
+
+    /// Turn a partially solved day into the final day, which is solved with part 1 alone.
+    pub fn into_final(self) -> Self {
+        match self {
+            Self::Partial(day) => Self::Final(day),
+            day => day,
+        }
+    }
+
     pub fn is_unsolved(&self) -> bool {
         match self {
             Self::Unsolved(_) => true,
             Self::Partial(_) => false,
             Self::Solved(_) => false,
+            Self::Final(_) => false,
         }
     }
 
@@ -48,21 +71,29 @@ impl Day {
             Self::Unsolved(_) => false,
             Self::Partial(_) => true,
             Self::Solved(_) => true,
+            Self::Final(_) => true,
         }
     }
 
+    /// Whether every part of the day has a solution, the final day has only one.
     pub fn is_solved(&self) -> bool {
         match self {
             Self::Unsolved(_) => false,
             Self::Partial(_) => false,
             Self::Solved(_) => true,
+            Self::Final(_) => true,
         }
     }
 
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Final(_))
+    }
+
     pub fn try_into_partial(&self) -> Option<PartialDay> {
         match self {
             Self::Partial(day) => Some(PartialDay::Partial(day.clone())),
             Self::Solved(day) => Some(PartialDay::Solved(day.clone())),
+            Self::Final(day) => Some(PartialDay::Final(day.clone())),
             _ => None,
         }
     }
@@ -77,7 +108,7 @@ impl Day {
     pub fn year(&self) -> u16 {
         match self {
             Self::Unsolved(day) => day.year(),
-            Self::Partial(day) => day.year(),
+            Self::Partial(day) | Self::Final(day) => day.year(),
             Self::Solved(day) => day.year(),
         }
     }
@@ -85,7 +116,7 @@ impl Day {
     pub fn day(&self) -> u8 {
         match self {
             Self::Unsolved(day) => day.day(),
-            Self::Partial(day) => day.day(),
+            Self::Partial(day) | Self::Final(day) => day.day(),
             Self::Solved(day) => day.day(),
         }
     }
@@ -93,26 +124,94 @@ impl Day {
     pub fn alt(&self) -> Option<&'static str> {
         match self {
             Self::Unsolved(day) => day.alt(),
-            Self::Partial(day) => day.alt(),
+            Self::Partial(day) | Self::Final(day) => day.alt(),
             Self::Solved(day) => day.alt(),
         }
     }
+
+    /// Check the solution against the examples of the puzzle.
+    pub fn check_examples(&self) -> Vec<ExampleFailure> {
+        match self {
+            Self::Unsolved(_) => Vec::new(),
+            Self::Partial(day) | Self::Final(day) => day.check_examples(),
+            Self::Solved(day) => day.check_examples(),
+        }
+    }
 }
 
 impl PartialDay {
     pub fn year(&self) -> u16 {
         match self {
-            Self::Partial(day) => day.year(),
+            Self::Partial(day) | Self::Final(day) => day.year(),
             Self::Solved(day) => day.year(),
         }
     }
 
     pub fn day(&self) -> u8 {
         match self {
-            Self::Partial(day) => day.day(),
+            Self::Partial(day) | Self::Final(day) => day.day(),
             Self::Solved(day) => day.day(),
         }
     }
+
+    pub fn alt(&self) -> Option<&'static str> {
+        match self {
+            Self::Partial(day) | Self::Final(day) => day.alt(),
+            Self::Solved(day) => day.alt(),
+        }
+    }
+
+    /// The parts of the day which have a solution.
+    pub fn parts(&self) -> &'static [Part] {
+        match self {
+            Self::Partial(_) | Self::Final(_) => &[Part::Part1],
+            Self::Solved(_) => &Part::ALL,
+        }
+    }
+
+    /// Whether every part of the day has a solution, the final day has only one.
+    pub fn is_complete(&self) -> bool {
+        match self {
+            Self::Partial(_) => false,
+            Self::Solved(_) | Self::Final(_) => true,
+        }
+    }
+
+    /// Run the given part of the day.
+    pub async fn run(&self, part: Part, source: &dyn InputSource) -> Result<SolutionOutput> {
+        match (self, part) {
+            (Self::Partial(day) | Self::Final(day), Part::Part1) => day.run_part1(source).await,
+            (Self::Partial(_), Part::Part2) => bail!("Part 2 of day {} is not solved yet", self.day()),
+            (Self::Final(_), Part::Part2) => {
+                bail!("Day {} is the last day, its part 2 is the star for finishing every other day", self.day())
+            }
+            (Self::Solved(day), Part::Part1) => day.run_part1(source).await,
+            (Self::Solved(day), Part::Part2) => day.run_part2(source).await,
+        }
+    }
+
+    /// Run every solved part of the day, sharing the parsed input between the parts if possible.
+    ///
+    /// Returns an error if the input could not be fetched or the shared parse failed.
+    pub async fn run_parts(&self, source: &dyn InputSource) -> Result<Vec<(Part, Result<SolutionOutput>)>> {
+        let outputs = match self {
+            Self::Partial(day) | Self::Final(day) => vec![day.run_part1(source).await],
+            Self::Solved(day) => day.run_both(source).await?.into(),
+        };
+
+        Ok(self.parts().iter().copied().zip(outputs).collect())
+    }
+
+    /// Benchmark every solved part of the day, sharing the parsed input between the parts if
+    /// possible.
+    pub async fn bench_parts(&self, source: &dyn InputSource, config: &BenchConfig) -> Result<Vec<(Part, PhaseReport)>> {
+        let reports = match self {
+            Self::Partial(day) | Self::Final(day) => vec![day.bench_part1(source, config).await?],
+            Self::Solved(day) => day.bench_both(source, config).await?.into(),
+        };
+
+        Ok(self.parts().iter().copied().zip(reports).collect())
+    }
 }
 
 impl fmt::Debug for Day {
@@ -124,6 +223,7 @@ impl fmt::Debug for Day {
             Self::Unsolved(_) => s.field("status", &"Unsolved"),
             Self::Partial(_) => s.field("status", &"Partial"),
             Self::Solved(_) => s.field("status", &"Solved"),
+            Self::Final(_) => s.field("status", &"Final"),
         };
         s.finish()
     }
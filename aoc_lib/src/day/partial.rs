@@ -2,8 +2,10 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use anyhow::Result;
 
-use crate::{AocClient, SolutionPart1};
-use crate::puzzle::{SolutionPart1Ext};
+use crate::bench::{BenchConfig, PhaseReport};
+use crate::input_source::InputSource;
+use crate::SolutionPart1;
+use crate::puzzle::{ExampleFailure, SolutionOutput, SolutionPart1Ext};
 
 #[async_trait]
 pub trait PartialSolvedDayTrait: sealed::Sealed {
@@ -11,8 +13,10 @@ pub trait PartialSolvedDayTrait: sealed::Sealed {
     fn day(&self) -> u8;
     fn alt(&self) -> Option<&'static str>;
 
-    async fn run_part1(&self, client: &AocClient) -> Result<()>;
-    async fn bench_part1(&self, client: &AocClient, iterations: u32) -> Result<()>;
+    async fn run_part1(&self, source: &dyn InputSource) -> Result<SolutionOutput>;
+    async fn bench_part1(&self, source: &dyn InputSource, config: &BenchConfig) -> Result<PhaseReport>;
+
+    fn check_examples(&self) -> Vec<ExampleFailure>;
 }
 
 pub struct PartialSolvedDay<P: SolutionPart1> {
@@ -51,12 +55,16 @@ impl<P: SolutionPart1> PartialSolvedDayTrait for PartialSolvedDay<P> {
         P::ALT
     }
 
-    async fn run_part1(&self, client: &AocClient) -> Result<()> {
-        P::run_part1(client).await
+    async fn run_part1(&self, source: &dyn InputSource) -> Result<SolutionOutput> {
+        P::run_part1(source).await
+    }
+
+    async fn bench_part1(&self, source: &dyn InputSource, config: &BenchConfig) -> Result<PhaseReport> {
+        P::bench_part1(source, config).await
     }
 
-    async fn bench_part1(&self, client: &AocClient, iterations: u32) -> Result<()> {
-        P::bench_part1(client, iterations).await
+    fn check_examples(&self) -> Vec<ExampleFailure> {
+        P::check_part1_examples()
     }
 }
 
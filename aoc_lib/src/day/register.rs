@@ -0,0 +1,194 @@
+//! Pick the `Day` constructor matching the parts a puzzle type solves, see `register_day!`.
+//!
+//! This uses autoref specialization: the method lookup of `(&&&Registration::<P>::new())`
+//! tries `RegisterSolved` first, then `RegisterPartial`, then `RegisterUnsolved`, and takes the
+//! first trait whose bounds `P` satisfies.
+
+use std::marker::PhantomData;
+
+use crate::day::Day;
+use crate::puzzle::Puzzle;
+use crate::{SolutionPart1, SolutionPart2};
+
+pub struct Registration<P>(PhantomData<P>);
+
+impl<P> Registration<P> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+pub trait RegisterSolved {
+    fn register(&self) -> Option<Day>;
+}
+
+impl<P: SolutionPart1 + SolutionPart2> RegisterSolved for &&Registration<P> {
+    fn register(&self) -> Option<Day> {
+        Some(Day::solved::<P>())
+    }
+}
+
+pub trait RegisterPartial {
+    fn register(&self) -> Option<Day>;
+}
+
+impl<P: SolutionPart1> RegisterPartial for &Registration<P> {
+    fn register(&self) -> Option<Day> {
+        Some(Day::partial::<P>())
+    }
+}
+
+pub trait RegisterUnsolved {
+    fn register(&self) -> Option<Day>;
+}
+
+/// Puzzles without any solution yet are left out, their day stays unsolved.
+impl<P: Puzzle> RegisterUnsolved for Registration<P> {
+    fn register(&self) -> Option<Day> {
+        None
+    }
+}
+
+/// The `Day` of the puzzle type, solved or partial depending on the solution traits it
+/// implements, `None` if it implements neither.
+#[macro_export]
+macro_rules! register_day {
+    ($puzzle:ty) => {{
+        #[allow(unused_imports)]
+        use $crate::day::register::{RegisterPartial, RegisterSolved, RegisterUnsolved};
+        (&&&$crate::day::register::Registration::<$puzzle>::new()).register()
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day::Day;
+    use crate::puzzle::{Example, PuzzleInput};
+    use crate::year::Year;
+    use crate::{impl_puzzle_result, Puzzle, SolutionPart1, SolutionPart2};
+
+    struct Input;
+
+    impl PuzzleInput for Input {
+        fn from_input(_input: &str) -> anyhow::Result<Self> {
+            Ok(Self)
+        }
+    }
+
+    struct Output {
+        answer: u32,
+    }
+
+    impl_puzzle_result!(Output, "{}", answer);
+
+    macro_rules! puzzle {
+        ($name:ident) => {
+            puzzle!($name, None);
+        };
+        ($name:ident, $alt:expr) => {
+            struct $name;
+
+            impl Puzzle for $name {
+                const DAY: u8 = 1;
+                const YEAR: u16 = 2024;
+                const ALT: Option<&'static str> = $alt;
+                const EXAMPLES: &'static [Example] = &[];
+            }
+        };
+    }
+
+    puzzle!(Solved);
+    puzzle!(Partial);
+    puzzle!(Unsolved);
+    puzzle!(Alternative, Some("Alternative"));
+
+    impl SolutionPart1 for Solved {
+        type Input = Input;
+        type Result = Output;
+
+        fn solve(_input: Input) -> anyhow::Result<Output> {
+            Ok(Output { answer: 1 })
+        }
+    }
+
+    impl SolutionPart2 for Solved {
+        type Input = Input;
+        type Result = Output;
+
+        fn solve(_input: Input) -> anyhow::Result<Output> {
+            Ok(Output { answer: 2 })
+        }
+    }
+
+    impl SolutionPart1 for Partial {
+        type Input = Input;
+        type Result = Output;
+
+        fn solve(_input: Input) -> anyhow::Result<Output> {
+            Ok(Output { answer: 1 })
+        }
+    }
+
+    impl SolutionPart1 for Alternative {
+        type Input = Input;
+        type Result = Output;
+
+        fn solve(_input: Input) -> anyhow::Result<Output> {
+            Ok(Output { answer: 3 })
+        }
+    }
+
+    /// The last day of a short season.
+    struct LastDay;
+
+    impl Puzzle for LastDay {
+        const DAY: u8 = 12;
+        const YEAR: u16 = 2025;
+    }
+
+    impl SolutionPart1 for LastDay {
+        type Input = Input;
+        type Result = Output;
+
+        fn solve(_input: Input) -> anyhow::Result<Output> {
+            Ok(Output { answer: 12 })
+        }
+    }
+
+    #[test]
+    fn test_register_day() {
+        assert!(register_day!(Solved).is_some_and(|day| day.is_solved()));
+        assert!(register_day!(Partial).is_some_and(|day| day.is_partial() && !day.is_solved()));
+        assert!(register_day!(Unsolved).is_none());
+    }
+
+    #[test]
+    fn test_final_day() {
+        let mut year = Year::new(2025);
+        year.add_day(register_day!(LastDay).unwrap()).unwrap();
+        assert!(year.get_day(12).is_ok_and(Day::is_final));
+
+        let mut year = Year::with_day_count(2024, 1);
+        year.add_day(register_day!(Partial).unwrap()).unwrap();
+        assert!(year.get_day(1).is_ok_and(Day::is_final));
+    }
+
+    #[test]
+    fn test_alternative_keeps_default() {
+        // An alternative registered after the solution doesn't replace it
+        let mut year = Year::new(2024);
+        year.add_day(register_day!(Solved).unwrap()).unwrap();
+        year.add_day(register_day!(Alternative).unwrap()).unwrap();
+        let (day, alternatives) = year.get_day_full(1).unwrap();
+        assert!(day.alt().is_none() && day.is_solved());
+        assert!(alternatives.is_some_and(|alternatives| alternatives.contains_key("Alternative")));
+
+        // Without a solution yet the alternative stands in, until the solution is registered
+        let mut year = Year::new(2024);
+        year.add_day(register_day!(Alternative).unwrap()).unwrap();
+        assert_eq!(year.get_day(1).unwrap().alt(), Some("Alternative"));
+        year.add_day(register_day!(Solved).unwrap()).unwrap();
+        assert!(year.get_day(1).unwrap().alt().is_none());
+    }
+}
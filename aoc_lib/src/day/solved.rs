@@ -2,8 +2,10 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use anyhow::Result;
 
-use crate::{AocClient, SolutionPart1, SolutionPart2};
-use crate::puzzle::{SolutionPart1Ext, SolutionPart2Ext};
+use crate::bench::{BenchConfig, PhaseReport};
+use crate::input_source::InputSource;
+use crate::{SolutionPart1, SolutionPart2};
+use crate::puzzle::{ExampleFailure, SolutionExt, SolutionOutput, SolutionPart1Ext, SolutionPart2Ext};
 
 #[async_trait]
 pub trait SolvedDayTrait: sealed::Sealed {
@@ -11,11 +13,18 @@ pub trait SolvedDayTrait: sealed::Sealed {
     fn day(&self) -> u8;
     fn alt(&self) -> Option<&'static str>;
 
-    async fn run_part1(&self, client: &AocClient) -> Result<()>;
-    async fn bench_part1(&self, client: &AocClient, iterations: u32) -> Result<()>;
+    async fn run_part1(&self, source: &dyn InputSource) -> Result<SolutionOutput>;
+    async fn bench_part1(&self, source: &dyn InputSource, config: &BenchConfig) -> Result<PhaseReport>;
 
-    async fn run_part2(&self, client: &AocClient) -> Result<()>;
-    async fn bench_part2(&self, client: &AocClient, iterations: u32) -> Result<()>;
+    async fn run_part2(&self, source: &dyn InputSource) -> Result<SolutionOutput>;
+    async fn bench_part2(&self, source: &dyn InputSource, config: &BenchConfig) -> Result<PhaseReport>;
+
+    /// Run both parts, sharing the parsed input if possible.
+    async fn run_both(&self, source: &dyn InputSource) -> Result<[Result<SolutionOutput>; 2]>;
+    /// Benchmark both parts, sharing the parsed input if possible.
+    async fn bench_both(&self, source: &dyn InputSource, config: &BenchConfig) -> Result<[PhaseReport; 2]>;
+
+    fn check_examples(&self) -> Vec<ExampleFailure>;
 }
 
 pub struct SolvedDay<P: SolutionPart1 + SolutionPart2> {
@@ -54,20 +63,34 @@ impl<P: SolutionPart1 + SolutionPart2> SolvedDayTrait for SolvedDay<P> {
         P::ALT
     }
 
-    async fn run_part1(&self, client: &AocClient) -> Result<()> {
-        P::run_part1(client).await
+    async fn run_part1(&self, source: &dyn InputSource) -> Result<SolutionOutput> {
+        P::run_part1(source).await
+    }
+
+    async fn bench_part1(&self, source: &dyn InputSource, config: &BenchConfig) -> Result<PhaseReport> {
+        P::bench_part1(source, config).await
+    }
+
+    async fn run_part2(&self, source: &dyn InputSource) -> Result<SolutionOutput> {
+        P::run_part2(source).await
+    }
+
+    async fn bench_part2(&self, source: &dyn InputSource, config: &BenchConfig) -> Result<PhaseReport> {
+        P::bench_part2(source, config).await
     }
 
-    async fn bench_part1(&self, client: &AocClient, iterations: u32) -> Result<()> {
-        P::bench_part1(client, iterations).await
+    async fn run_both(&self, source: &dyn InputSource) -> Result<[Result<SolutionOutput>; 2]> {
+        P::run_both(source).await
     }
 
-    async fn run_part2(&self, client: &AocClient) -> Result<()> {
-        P::run_part2(client).await
+    async fn bench_both(&self, source: &dyn InputSource, config: &BenchConfig) -> Result<[PhaseReport; 2]> {
+        P::bench_both(source, config).await
     }
 
-    async fn bench_part2(&self, client: &AocClient, iterations: u32) -> Result<()> {
-        P::bench_part2(client, iterations).await
+    fn check_examples(&self) -> Vec<ExampleFailure> {
+        let mut failures = P::check_part1_examples();
+        failures.extend(P::check_part2_examples());
+        failures
     }
 }
 
@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Part;
+use super::html::{Element, Node};
+
+/// An example input found in a puzzle description, with the answers emphasized after it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtractedExample {
+    input: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+/// The examples of a day as they are stored in the cache.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct StoredExamples {
+    /// The number of parts the examples were extracted from.
+    pub(crate) parts: usize,
+    pub(crate) examples: Vec<ExtractedExample>,
+}
+
+/// The answers longer than this are not answers, but e.g. intermediate states of the example.
+const MAX_ANSWER_LEN: usize = 40;
+
+impl ExtractedExample {
+    pub fn new(input: impl Into<String>) -> Self {
+        Self {
+            input: input.into(),
+            part1: None,
+            part2: None,
+        }
+    }
+
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The answer the description gives for the example, if one was found.
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::Part1 => self.part1.as_deref(),
+            Part::Part2 => self.part2.as_deref(),
+        }
+    }
+
+    fn set_expected(&mut self, part: Part, answer: String) {
+        match part {
+            Part::Part1 => self.part1 = Some(answer),
+            Part::Part2 => self.part2 = Some(answer),
+        }
+    }
+}
+
+enum Event {
+    /// A `<pre><code>` block, a candidate example input.
+    Block(String),
+    /// An emphasized code snippet, like `<code><em>11</em></code>`, a candidate answer.
+    Answer(String),
+}
+
+/// Find the example inputs of the parts, and the answers emphasized after them.
+///
+/// The last answer after an example is taken, as the descriptions end with the answer of the
+/// example. Answers of part 2 before any new example belong to the example of part 1 which has
+/// an answer, because part 2 usually reuses it.
+pub(super) fn extract(parts: &[Element]) -> Vec<ExtractedExample> {
+    let mut examples: Vec<ExtractedExample> = Vec::new();
+
+    for (part, article) in Part::ALL.into_iter().zip(parts) {
+        let mut events = Vec::new();
+        collect_events(article.children(), &mut events);
+
+        let mut current = match part {
+            Part::Part1 => None,
+            Part::Part2 => examples.iter()
+                .position(|example| example.part1.is_some())
+                .or((!examples.is_empty()).then_some(0)),
+        };
+
+        for event in events {
+            match event {
+                Event::Block(input) => {
+                    current = Some(examples.len());
+                    examples.push(ExtractedExample::new(input));
+                }
+                Event::Answer(answer) => {
+                    if let Some(example) = current.and_then(|current| examples.get_mut(current)) {
+                        example.set_expected(part, answer);
+                    }
+                }
+            }
+        }
+    }
+
+    examples
+}
+
+fn collect_events(nodes: &[Node], events: &mut Vec<Event>) {
+    for node in nodes {
+        let Node::Element(element) = node else {
+            continue;
+        };
+
+        match element.tag() {
+            "pre" => events.push(Event::Block(element.text())),
+            "code" | "em" if is_answer(element) => {
+                let answer = element.text().trim().to_string();
+                if !answer.is_empty() && answer.len() <= MAX_ANSWER_LEN && !answer.contains('\n') {
+                    events.push(Event::Answer(answer));
+                }
+            }
+            _ => collect_events(element.children(), events),
+        }
+    }
+}
+
+/// Whether the element is a `<code>` with an `<em>` inside, or the other way around.
+fn is_answer(element: &Element) -> bool {
+    let inner = match element.tag() {
+        "code" => "em",
+        _ => "code",
+    };
+
+    !element.find_all(inner).is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::description::PuzzleDescription;
+
+    #[test]
+    fn test_extract() {
+        let page = r#"
+<article class="day-desc"><h2>--- Day 1: Test ---</h2>
+<p>For example:</p><pre><code>3   4
+4   3
+</code></pre>
+<p>The distance is <code>1</code>, then <code>2</code>.</p>
+<p>In total, the distance is <code><em>11</em></code>.</p>
+<p>Another example:</p><pre><code>1 1
+</code></pre>
+<p>Here it is <em><code>0</code></em>.</p>
+</article>
+<article class="day-desc"><h2>--- Part Two ---</h2>
+<p>So, for the example above, the score is <code><em>31</em></code>.</p>
+</article>"#;
+
+        let examples = PuzzleDescription::parse(page).examples();
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].input(), "3   4\n4   3\n");
+        assert_eq!(examples[0].expected(Part::Part1), Some("11"));
+        assert_eq!(examples[0].expected(Part::Part2), Some("31"));
+        assert_eq!(examples[1].expected(Part::Part1), Some("0"));
+        assert_eq!(examples[1].expected(Part::Part2), None);
+    }
+}
@@ -0,0 +1,295 @@
+//! A small and forgiving html parser, just enough for the pages of the aoc website.
+
+/// A node of the parsed document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Element {
+    tag: String,
+    attrs: Vec<(String, String)>,
+    children: Vec<Node>,
+}
+
+/// Elements which never have any content or closing tag.
+const VOID_TAGS: &[&str] = &["area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "wbr"];
+/// Elements whose content is not html.
+const RAW_TAGS: &[&str] = &["script", "style"];
+
+impl Element {
+    fn new(tag: String, attrs: Vec<(String, String)>) -> Self {
+        Self { tag, attrs, children: Vec::new() }
+    }
+
+    /// The lowercase tag name.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs.iter()
+            .find(|(attr, _)| attr == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn has_class(&self, class: &str) -> bool {
+        self.attr("class").is_some_and(|classes| classes.split_whitespace().any(|c| c == class))
+    }
+
+    pub fn children(&self) -> &[Node] {
+        &self.children
+    }
+
+    /// The text of all descendants, without any markup.
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        collect_text(&self.children, &mut text);
+        text
+    }
+
+    /// All descendants with the given tag, in document order.
+    pub fn find_all<'a>(&'a self, tag: &str) -> Vec<&'a Element> {
+        let mut found = Vec::new();
+        find_all(&self.children, tag, &mut found);
+        found
+    }
+}
+
+fn collect_text(nodes: &[Node], text: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(t) => text.push_str(t),
+            Node::Element(element) => collect_text(&element.children, text),
+        }
+    }
+}
+
+fn find_all<'a>(nodes: &'a [Node], tag: &str, found: &mut Vec<&'a Element>) {
+    for node in nodes {
+        if let Node::Element(element) = node {
+            if element.tag == tag {
+                found.push(element);
+            }
+            find_all(&element.children, tag, found);
+        }
+    }
+}
+
+/// Parse the document into a root element, which has the tag `#root`.
+///
+/// Unclosed elements are closed at the end of their parent, stray closing tags are ignored.
+pub fn parse(html: &str) -> Element {
+    let mut stack = vec![Element::new("#root".to_string(), Vec::new())];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_text(&mut stack, rest);
+            break;
+        };
+        push_text(&mut stack, &rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            push_text(&mut stack, rest);
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            close(&mut stack, &name.trim().to_ascii_lowercase());
+            continue;
+        }
+
+        let self_closing = tag.ends_with('/');
+        let (name, attrs) = parse_tag(tag.trim_end_matches('/'));
+        if name.is_empty() {
+            push_text(&mut stack, &format!("<{}>", tag));
+            continue;
+        }
+
+        if RAW_TAGS.contains(&name.as_str()) {
+            let closing = format!("</{}", name);
+            let end = rest.to_ascii_lowercase().find(&closing).unwrap_or(rest.len());
+            let mut element = Element::new(name, attrs);
+            element.children.push(Node::Text(rest[..end].to_string()));
+            push_node(&mut stack, Node::Element(element));
+            rest = &rest[end..];
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+
+        let element = Element::new(name, attrs);
+        if self_closing || VOID_TAGS.contains(&element.tag.as_str()) {
+            push_node(&mut stack, Node::Element(element));
+        } else {
+            stack.push(element);
+        }
+    }
+
+    while stack.len() > 1 {
+        let element = stack.pop().expect("The stack has more than one element");
+        push_node(&mut stack, Node::Element(element));
+    }
+
+    stack.pop().expect("The root is always on the stack")
+}
+
+fn push_node(stack: &mut [Element], node: Node) {
+    stack.last_mut().expect("The root is always on the stack").children.push(node);
+}
+
+fn push_text(stack: &mut [Element], text: &str) {
+    if text.is_empty() {
+        return;
+    }
+
+    let parent = stack.last_mut().expect("The root is always on the stack");
+    let text = decode_entities(text);
+    match parent.children.last_mut() {
+        Some(Node::Text(previous)) => previous.push_str(&text),
+        _ => parent.children.push(Node::Text(text)),
+    }
+}
+
+/// Close the innermost open element with the tag, and every element opened inside of it.
+fn close(stack: &mut Vec<Element>, tag: &str) {
+    let Some(pos) = stack.iter().rposition(|element| element.tag == tag) else {
+        return;
+    };
+    if pos == 0 {
+        return;
+    }
+
+    while stack.len() > pos {
+        let element = stack.pop().expect("The stack is longer than pos");
+        push_node(stack, Node::Element(element));
+    }
+}
+
+/// Split the inside of an opening tag into the lowercase name and the attributes.
+fn parse_tag(tag: &str) -> (String, Vec<(String, String)>) {
+    let tag = tag.trim();
+    let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+    let name = tag[..name_end].to_ascii_lowercase();
+
+    let mut attrs = Vec::new();
+    let mut rest = tag[name_end..].trim_start();
+    while !rest.is_empty() {
+        let key_end = rest.find(|c: char| c == '=' || c.is_whitespace()).unwrap_or(rest.len());
+        let key = rest[..key_end].to_ascii_lowercase();
+        rest = rest[key_end..].trim_start();
+
+        let value = match rest.strip_prefix('=') {
+            Some(value) => {
+                let value = value.trim_start();
+                let (value, remaining) = match value.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let inner = &value[1..];
+                        let end = inner.find(quote).unwrap_or(inner.len());
+                        (&inner[..end], inner.get(end + 1..).unwrap_or(""))
+                    }
+                    _ => {
+                        let end = value.find(char::is_whitespace).unwrap_or(value.len());
+                        (&value[..end], &value[end..])
+                    }
+                };
+                rest = remaining.trim_start();
+                decode_entities(value)
+            }
+            None => String::new(),
+        };
+
+        if !key.is_empty() {
+            attrs.push((key, value));
+        }
+    }
+
+    (name, attrs)
+}
+
+/// Replace the character references with the characters they stand for.
+pub fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut res = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        res.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| decode_entity(&rest[1..end]).map(|c| (c, end)));
+        match decoded {
+            Some((c, end)) => {
+                res.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                res.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    res.push_str(rest);
+
+    res
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(num) = entity.strip_prefix('#') {
+        let code = match num.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => num.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+
+    match entity {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let root = parse("<!DOCTYPE html><p class=\"a b\">x &lt; <em>y</em><br>z<script>if (a < b) {}</script></p></span><p>unclosed");
+
+        let paragraphs = root.find_all("p");
+        assert_eq!(paragraphs.len(), 2);
+        assert!(paragraphs[0].has_class("b"));
+        assert_eq!(paragraphs[0].find_all("em")[0].text(), "y");
+        assert_eq!(paragraphs[0].find_all("script")[0].text(), "if (a < b) {}");
+        assert_eq!(paragraphs[1].text(), "unclosed");
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(decode_entities("a &amp;&#39;&#x41; &unknown; & b"), "a &'A &unknown; & b");
+    }
+}
@@ -0,0 +1,136 @@
+use crate::Part;
+
+mod examples;
+pub mod html;
+mod render;
+
+pub use examples::ExtractedExample;
+pub(crate) use examples::StoredExamples;
+
+use html::Element;
+use render::Renderer;
+
+/// The description of a puzzle, as shown on its page of the aoc website.
+#[derive(Debug, Clone)]
+pub struct PuzzleDescription {
+    title: Option<String>,
+    /// The `<article>` of every unlocked part.
+    parts: Vec<Element>,
+    /// The accepted answers, which the page shows below the solved parts.
+    answers: Vec<String>,
+}
+
+impl PuzzleDescription {
+    /// Extract the description from the html of the puzzle page.
+    pub fn parse(html: &str) -> Self {
+        let root = html::parse(html);
+
+        let parts = root.find_all("article")
+            .into_iter()
+            .filter(|article| article.has_class("day-desc"))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let title = parts.first()
+            .and_then(|part| part.find_all("h2").into_iter().next())
+            .map(|heading| heading.text().trim().trim_matches('-').trim().to_string());
+
+        let answers = root.find_all("p")
+            .into_iter()
+            .filter(|p| p.text().starts_with("Your puzzle answer was"))
+            .filter_map(|p| p.find_all("code").into_iter().next().map(Element::text))
+            .collect();
+
+        Self { title, parts, answers }
+    }
+
+    /// The title of the puzzle, e.g. `Day 1: Historian Hysteria`.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// The articles of the unlocked parts.
+    pub fn parts(&self) -> &[Element] {
+        &self.parts
+    }
+
+    /// Whether the description of the part is unlocked.
+    pub fn has_part(&self, part: Part) -> bool {
+        self.parts.len() >= part.level() as usize
+    }
+
+    /// The accepted answer of the part, if the page shows it.
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        self.answers.get(part.level() as usize - 1).map(String::as_str)
+    }
+
+    /// The example inputs of the unlocked parts, with the answers the description gives for them.
+    pub fn examples(&self) -> Vec<ExtractedExample> {
+        examples::extract(&self.parts)
+    }
+
+    /// Render the description as text wrapped to the width, with terminal colors if `styled`.
+    pub fn render(&self, width: usize, styled: bool) -> String {
+        let mut renderer = Renderer::new(width, styled);
+        for (part, article) in Part::ALL.into_iter().zip(&self.parts) {
+            renderer.element(article);
+            if let Some(answer) = self.answer(part) {
+                renderer.paragraph(&format!("Your puzzle answer was {}.", answer));
+            }
+        }
+
+        renderer.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><head><title>Day 1 - Advent of Code 2024</title></head><body><main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>The <em>Chief Historian</em> is always present.</p>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<ul><li>The first pair is <code>3</code>.</li></ul>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Now count.</p></article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_parse() {
+        let description = PuzzleDescription::parse(PAGE);
+        assert_eq!(description.title(), Some("Day 1: Historian Hysteria"));
+        assert!(description.has_part(Part::Part2));
+        assert_eq!(description.answer(Part::Part1), Some("1234"));
+        assert_eq!(description.answer(Part::Part2), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let text = PuzzleDescription::parse(PAGE).render(24, false);
+        let expected = "\
+--- Day 1: Historian
+Hysteria ---
+
+The Chief Historian is
+always present.
+
+For example:
+
+    3   4
+    4   3
+
+  - The first pair is 3.
+
+Your puzzle answer was
+1234.
+
+--- Part Two ---
+
+Now count.";
+        assert_eq!(text, expected);
+    }
+}
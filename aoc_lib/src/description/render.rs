@@ -0,0 +1,222 @@
+use crossterm::style::{ContentStyle, Stylize};
+
+use super::html::{Element, Node};
+
+/// Renders the html of a puzzle description as wrapped terminal text.
+pub(super) struct Renderer {
+    width: usize,
+    styled: bool,
+    lines: Vec<String>,
+    line: String,
+    line_len: usize,
+    /// Whether whitespace was seen since the last word.
+    space: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Style {
+    heading: bool,
+    emphasis: bool,
+    star: bool,
+    code: bool,
+    link: bool,
+}
+
+impl Renderer {
+    pub(super) fn new(width: usize, styled: bool) -> Self {
+        Self {
+            width: width.max(20),
+            styled,
+            lines: Vec::new(),
+            line: String::new(),
+            line_len: 0,
+            space: false,
+        }
+    }
+
+    pub(super) fn finish(mut self) -> String {
+        self.flush();
+        while self.lines.last().is_some_and(|line| line.is_empty()) {
+            self.lines.pop();
+        }
+
+        self.lines.join("\n")
+    }
+
+    pub(super) fn element(&mut self, element: &Element) {
+        self.block(element, Style::default());
+    }
+
+    /// Render a single paragraph of plain text.
+    pub(super) fn paragraph(&mut self, text: &str) {
+        self.flush();
+        self.inline_text(text, Style { emphasis: true, ..Style::default() });
+        self.end_block();
+    }
+
+    fn block(&mut self, element: &Element, style: Style) {
+        match element.tag() {
+            "h2" => {
+                self.flush();
+                self.inline(element.children(), Style { heading: true, ..style });
+                self.end_block();
+            }
+            "p" => {
+                self.flush();
+                self.inline(element.children(), style);
+                self.end_block();
+            }
+            "pre" => {
+                self.flush();
+                self.pre(element);
+                self.end_block();
+            }
+            "ul" | "ol" => {
+                self.flush();
+                for item in element.children().iter().filter_map(as_element).filter(|item| item.tag() == "li") {
+                    self.push_word("  - ".to_string(), 4);
+                    self.space = false;
+                    self.inline(item.children(), style);
+                    self.flush();
+                }
+                self.end_block();
+            }
+            _ => self.inline(element.children(), style),
+        }
+    }
+
+    fn inline(&mut self, nodes: &[Node], style: Style) {
+        for node in nodes {
+            match node {
+                Node::Text(text) => self.inline_text(text, style),
+                Node::Element(element) => match element.tag() {
+                    "h2" | "p" | "pre" | "ul" | "ol" => self.block(element, style),
+                    "br" => self.flush(),
+                    "em" => self.inline(element.children(), Style {
+                        emphasis: true,
+                        star: style.star || element.has_class("star"),
+                        ..style
+                    }),
+                    "code" => self.inline(element.children(), Style { code: true, ..style }),
+                    "a" => self.inline(element.children(), Style { link: true, ..style }),
+                    "script" | "style" => {}
+                    _ => self.inline(element.children(), style),
+                },
+            }
+        }
+    }
+
+    fn inline_text(&mut self, text: &str, style: Style) {
+        if text.starts_with(char::is_whitespace) {
+            self.space = true;
+        }
+
+        let mut words = text.split_whitespace().peekable();
+        while let Some(word) = words.next() {
+            self.push_word(self.apply(style, word), word.chars().count());
+            if words.peek().is_some() {
+                self.space = true;
+            }
+        }
+
+        if text.ends_with(char::is_whitespace) {
+            self.space = true;
+        }
+    }
+
+    /// Append a word to the current line, starting a new line if it doesn't fit.
+    fn push_word(&mut self, word: String, len: usize) {
+        let space = usize::from(self.space && self.line_len > 0);
+        if self.line_len > 0 && self.line_len + space + len > self.width {
+            self.flush();
+        } else if space == 1 {
+            self.line.push(' ');
+            self.line_len += 1;
+        }
+
+        self.line.push_str(&word);
+        self.line_len += len;
+        self.space = false;
+    }
+
+    /// Render preformatted text, keeping its lines and indenting it.
+    fn pre(&mut self, element: &Element) {
+        let mut line = String::new();
+        let mut lines = Vec::new();
+        pre_lines(element.children(), Style { code: true, ..Style::default() }, self, &mut line, &mut lines);
+        if !line.is_empty() {
+            lines.push(line);
+        }
+
+        self.lines.extend(lines.into_iter().map(|line| format!("    {}", line)));
+    }
+
+    fn flush(&mut self) {
+        if self.line_len > 0 {
+            self.lines.push(std::mem::take(&mut self.line));
+        }
+        self.line.clear();
+        self.line_len = 0;
+        self.space = false;
+    }
+
+    fn end_block(&mut self) {
+        self.flush();
+        if self.lines.last().is_some_and(|line| !line.is_empty()) {
+            self.lines.push(String::new());
+        }
+    }
+
+    fn apply(&self, style: Style, text: &str) -> String {
+        if !self.styled {
+            return text.to_string();
+        }
+
+        let mut content = ContentStyle::new();
+        if style.heading || style.emphasis {
+            content = content.bold();
+        }
+        if style.star {
+            content = content.yellow();
+        } else if style.code {
+            content = content.cyan();
+        }
+        if style.link {
+            content = content.underlined();
+        }
+
+        content.apply(text).to_string()
+    }
+}
+
+fn pre_lines(nodes: &[Node], style: Style, renderer: &Renderer, line: &mut String, lines: &mut Vec<String>) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => {
+                let mut parts = text.split('\n').peekable();
+                while let Some(part) = parts.next() {
+                    if !part.is_empty() {
+                        line.push_str(&renderer.apply(style, part));
+                    }
+                    if parts.peek().is_some() {
+                        lines.push(std::mem::take(line));
+                    }
+                }
+            }
+            Node::Element(element) => {
+                let style = match element.tag() {
+                    "em" => Style { emphasis: true, ..style },
+                    _ => style,
+                };
+                pre_lines(element.children(), style, renderer, line, lines);
+            }
+        }
+    }
+}
+
+fn as_element(node: &Node) -> Option<&Element> {
+    match node {
+        Node::Element(element) => Some(element),
+        Node::Text(_) => None,
+    }
+}
@@ -0,0 +1,130 @@
+use std::path::PathBuf;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use tokio::io::AsyncReadExt;
+use tokio::sync::OnceCell;
+
+use crate::aoc_client::MissingInput;
+use crate::cache::Cache;
+use crate::AocClient;
+
+/// Where the inputs of the puzzles come from.
+#[async_trait]
+pub trait InputSource: Send + Sync {
+    /// Get the input of the given puzzle.
+    async fn get_input(&self, year: u16, day: u8) -> Result<String>;
+}
+
+/// The aoc server, inputs are cached on disk and only fetched if they are not cached yet.
+#[async_trait]
+impl InputSource for AocClient {
+    async fn get_input(&self, year: u16, day: u8) -> Result<String> {
+        self.get_challenge(year, day).await
+    }
+}
+
+/// The inputs which are already cached on disk, the server is never contacted.
+///
+/// A cache miss is a [`MissingInput`] error.
+#[derive(Debug, Clone)]
+pub struct CacheInput {
+    cache: Cache,
+}
+
+impl CacheInput {
+    pub fn new(cache: Cache) -> Self {
+        Self { cache }
+    }
+}
+
+#[async_trait]
+impl InputSource for CacheInput {
+    async fn get_input(&self, year: u16, day: u8) -> Result<String> {
+        self.cache.read_input(year, day)
+            .context("Failed to check the cache for the challenge")?
+            .ok_or_else(|| MissingInput { year, day }.into())
+    }
+}
+
+/// A single file, which is used as the input of every puzzle.
+#[derive(Debug, Clone)]
+pub struct FileInput {
+    path: PathBuf,
+}
+
+impl FileInput {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl InputSource for FileInput {
+    async fn get_input(&self, _year: u16, _day: u8) -> Result<String> {
+        tokio::fs::read_to_string(&self.path).await
+            .with_context(|| format!("Failed to read the input file {}", self.path.display()))
+    }
+}
+
+/// The standard input, which is read once and then used as the input of every puzzle.
+#[derive(Debug, Default)]
+pub struct StdinInput {
+    input: OnceCell<String>,
+}
+
+impl StdinInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl InputSource for StdinInput {
+    async fn get_input(&self, _year: u16, _day: u8) -> Result<String> {
+        let input = self.input.get_or_try_init(|| async {
+            let mut text = String::new();
+            tokio::io::stdin().read_to_string(&mut text).await
+                .context("Failed to read the input from stdin")?;
+            Ok::<_, anyhow::Error>(text)
+        }).await?;
+
+        Ok(input.clone())
+    }
+}
+
+/// A fixed text, e.g. an example from the puzzle description, used as the input of every puzzle.
+#[derive(Debug, Clone)]
+pub struct TextInput {
+    text: String,
+}
+
+impl TextInput {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into() }
+    }
+}
+
+#[async_trait]
+impl InputSource for TextInput {
+    async fn get_input(&self, _year: u16, _day: u8) -> Result<String> {
+        Ok(self.text.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_file_input() {
+        let path = std::env::temp_dir().join(format!("aoc-file-input-{}.txt", std::process::id()));
+        std::fs::write(&path, "1 2 3\n").unwrap();
+
+        let source = FileInput::new(&path);
+        assert_eq!(source.get_input(2024, 1).await.unwrap(), "1 2 3\n");
+        assert_eq!(source.get_input(2024, 2).await.unwrap(), "1 2 3\n");
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(source.get_input(2024, 1).await.is_err());
+    }
+}
@@ -21,4 +21,34 @@ pub fn print_debug<T: Display>(msg: T) {
         Print("\n"),
         ResetColor
     ).expect("Failed to print debug message");
-}
\ No newline at end of file
+}
+
+pub fn print_success<T: Display>(msg: T) {
+    execute!(
+        io::stdout(),
+        SetForegroundColor(Color::Green),
+        Print(msg),
+        Print("\n"),
+        ResetColor
+    ).expect("Failed to print success message");
+}
+
+/// Print a diagnostic of the library to stderr, so it never ends up in machine-readable output
+/// on stdout.
+pub fn print_warning<T: Display>(msg: T) {
+    execute!(
+        io::stderr(),
+        SetForegroundColor(Color::Yellow),
+        Print(msg),
+        Print("\n"),
+        ResetColor
+    ).expect("Failed to print warning message");
+}
+
+/// The width to wrap text at, the terminal width but at most 100 columns to keep it readable.
+pub fn text_width() -> usize {
+    crossterm::terminal::size()
+        .map(|(columns, _)| columns as usize)
+        .unwrap_or(80)
+        .min(100)
+}
@@ -0,0 +1,158 @@
+use std::collections::BTreeMap;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::Part;
+
+/// How long a fetched leaderboard is used before it is fetched again, the aoc website asks to
+/// poll it at most every 15 minutes.
+pub const LEADERBOARD_TTL: Duration = Duration::minutes(15);
+
+/// A private leaderboard, as returned by its json endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Leaderboard {
+    event: String,
+    owner_id: u64,
+    members: BTreeMap<String, Member>,
+    /// When the leaderboard was fetched from the server, only known for cached leaderboards.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fetched_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Member {
+    id: u64,
+    name: Option<String>,
+    stars: u32,
+    local_score: u64,
+    #[serde(default)]
+    global_score: u64,
+    #[serde(default)]
+    last_star_ts: i64,
+    /// The stars per day and part, both keyed by their number.
+    #[serde(default)]
+    completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Star {
+    get_star_ts: i64,
+    #[serde(default)]
+    star_index: u64,
+}
+
+impl Leaderboard {
+    pub fn event(&self) -> &str {
+        &self.event
+    }
+
+    pub fn owner_id(&self) -> u64 {
+        self.owner_id
+    }
+
+    pub fn members(&self) -> impl Iterator<Item = &Member> {
+        self.members.values()
+    }
+
+    pub fn fetched_at(&self) -> Option<DateTime<Utc>> {
+        self.fetched_at
+    }
+
+    pub(crate) fn with_fetched_at(mut self, fetched_at: DateTime<Utc>) -> Self {
+        self.fetched_at = Some(fetched_at);
+        self
+    }
+
+    /// Whether the leaderboard was fetched recently enough that it shouldn't be fetched again.
+    pub fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        self.fetched_at.is_some_and(|fetched_at| now - fetched_at < LEADERBOARD_TTL)
+    }
+
+    /// The members ordered by their rank, by local score, then stars, then who got the last
+    /// star first.
+    pub fn ranking(&self) -> Vec<&Member> {
+        let mut members = self.members.values().collect::<Vec<_>>();
+        members.sort_by(|a, b| {
+            b.local_score.cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+                .then(a.id.cmp(&b.id))
+        });
+        members
+    }
+
+    /// The days on which any member got a star, in order.
+    pub fn days(&self) -> Vec<u8> {
+        let mut days = self.members.values()
+            .flat_map(|member| member.completion_day_level.keys())
+            .filter_map(|day| day.parse().ok())
+            .collect::<Vec<u8>>();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+}
+
+impl Member {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// The name of the member, anonymous members are named like on the website.
+    pub fn name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    pub fn stars(&self) -> u32 {
+        self.stars
+    }
+
+    pub fn local_score(&self) -> u64 {
+        self.local_score
+    }
+
+    pub fn global_score(&self) -> u64 {
+        self.global_score
+    }
+
+    /// When the member got the star of the part, `None` if they didn't get it yet.
+    pub fn star_time(&self, day: u8, part: Part) -> Option<DateTime<Utc>> {
+        self.completion_day_level.get(&day.to_string())?
+            .get(&part.level().to_string())
+            .and_then(|star| DateTime::from_timestamp(star.get_star_ts, 0))
+    }
+
+    /// How long the member took for part 2 after solving part 1.
+    pub fn part2_delta(&self, day: u8) -> Option<Duration> {
+        Some(self.star_time(day, Part::Part2)? - self.star_time(day, Part::Part1)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JSON: &str = r#"{"event":"2024","owner_id":1,"members":{
+        "1":{"id":1,"name":"alice","stars":3,"local_score":10,"global_score":0,"last_star_ts":1733040000,
+            "completion_day_level":{"1":{"1":{"get_star_ts":1733030000,"star_index":1},"2":{"get_star_ts":1733030600,"star_index":2}},
+                                    "2":{"1":{"get_star_ts":1733040000,"star_index":3}}}},
+        "2":{"id":2,"name":null,"stars":3,"local_score":10,"global_score":0,"last_star_ts":1733030000,"completion_day_level":{}}
+    }}"#;
+
+    #[test]
+    fn test_parse() {
+        let leaderboard: Leaderboard = serde_json::from_str(JSON).unwrap();
+
+        let ranking = leaderboard.ranking().into_iter().map(Member::name).collect::<Vec<_>>();
+        assert_eq!(ranking, ["(anonymous user #2)", "alice"]);
+        assert_eq!(leaderboard.days(), [1, 2]);
+
+        let alice = leaderboard.members().find(|member| member.id() == 1).unwrap();
+        assert_eq!(alice.part2_delta(1), Some(Duration::minutes(10)));
+        assert_eq!(alice.part2_delta(2), None);
+        assert!(alice.star_time(2, Part::Part1).is_some());
+    }
+}
@@ -0,0 +1,248 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+
+use crate::aoc_client::SubmissionVerdict;
+use crate::puzzle::SolutionOutput;
+use crate::Part;
+
+/// The answers which were submitted for a single day, and what the server said about them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AnswerLedger {
+    #[serde(default)]
+    part1: PartLedger,
+    #[serde(default)]
+    part2: PartLedger,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PartLedger {
+    /// The answer the server accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    accepted: Option<String>,
+    /// Every answer the server rejected, in the order they were submitted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rejected: Vec<String>,
+    /// The highest answer which was too low, the real answer is above this.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    lower_bound: Option<i128>,
+    /// The lowest answer which was too high, the real answer is below this.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    upper_bound: Option<i128>,
+}
+
+/// The result of comparing an answer against the ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LedgerCheck {
+    /// The answer matches the accepted answer.
+    Correct,
+    /// An answer was accepted before, and this one differs from it.
+    Regression { accepted: String },
+    /// The answer was already rejected by the server.
+    KnownWrong,
+    /// The answer is at or above an answer the server reported as too high.
+    TooHigh { bound: i128 },
+    /// The answer is at or below an answer the server reported as too low.
+    TooLow { bound: i128 },
+    /// Nothing is known about the answer.
+    Unknown,
+}
+
+impl AnswerLedger {
+    pub fn part(&self, part: Part) -> &PartLedger {
+        match part {
+            Part::Part1 => &self.part1,
+            Part::Part2 => &self.part2,
+        }
+    }
+
+    pub fn part_mut(&mut self, part: Part) -> &mut PartLedger {
+        match part {
+            Part::Part1 => &mut self.part1,
+            Part::Part2 => &mut self.part2,
+        }
+    }
+
+    /// Compare the answer for the given part against the ledger.
+    pub fn check(&self, part: Part, answer: &str) -> LedgerCheck {
+        self.part(part).check(answer)
+    }
+
+    /// Compare the answer of a run against the ledger, e.g. to flag a regression or an answer
+    /// known to be wrong without contacting the server.
+    pub fn check_output(&self, part: Part, output: &SolutionOutput) -> LedgerCheck {
+        self.check(part, &output.answer().to_string())
+    }
+
+    /// Record the verdict the server gave for the answer.
+    ///
+    /// Returns whether the ledger changed.
+    pub fn record(&mut self, part: Part, answer: &str, verdict: SubmissionVerdict) -> bool {
+        self.part_mut(part).record(answer, verdict)
+    }
+}
+
+impl PartLedger {
+    pub fn accepted(&self) -> Option<&str> {
+        self.accepted.as_deref()
+    }
+
+    pub fn rejected(&self) -> &[String] {
+        &self.rejected
+    }
+
+    pub fn lower_bound(&self) -> Option<i128> {
+        self.lower_bound
+    }
+
+    pub fn upper_bound(&self) -> Option<i128> {
+        self.upper_bound
+    }
+
+    /// Compare the answer against the ledger.
+    pub fn check(&self, answer: &str) -> LedgerCheck {
+        let answer = answer.trim();
+
+        if let Some(accepted) = &self.accepted {
+            return if accepted == answer {
+                LedgerCheck::Correct
+            } else {
+                LedgerCheck::Regression { accepted: accepted.clone() }
+            };
+        }
+
+        if self.rejected.iter().any(|rejected| rejected == answer) {
+            return LedgerCheck::KnownWrong;
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            if let Some(bound) = self.upper_bound.filter(|&bound| value >= bound) {
+                return LedgerCheck::TooHigh { bound };
+            }
+            if let Some(bound) = self.lower_bound.filter(|&bound| value <= bound) {
+                return LedgerCheck::TooLow { bound };
+            }
+        }
+
+        LedgerCheck::Unknown
+    }
+
+    /// Record the verdict the server gave for the answer.
+    ///
+    /// Returns whether the ledger changed.
+    pub fn record(&mut self, answer: &str, verdict: SubmissionVerdict) -> bool {
+        let answer = answer.trim();
+        let value = answer.parse::<i128>().ok();
+
+        match verdict {
+            SubmissionVerdict::Correct => {
+                let changed = self.accepted.as_deref() != Some(answer);
+                self.accepted = Some(answer.to_string());
+                changed
+            }
+            SubmissionVerdict::TooHigh | SubmissionVerdict::TooLow | SubmissionVerdict::Wrong => {
+                match (verdict, value) {
+                    (SubmissionVerdict::TooHigh, Some(value)) => {
+                        self.upper_bound = Some(self.upper_bound.map_or(value, |bound| bound.min(value)));
+                    }
+                    (SubmissionVerdict::TooLow, Some(value)) => {
+                        self.lower_bound = Some(self.lower_bound.map_or(value, |bound| bound.max(value)));
+                    }
+                    _ => {}
+                }
+
+                if self.rejected.iter().any(|rejected| rejected == answer) {
+                    return false;
+                }
+                self.rejected.push(answer.to_string());
+                true
+            }
+            SubmissionVerdict::RateLimited { .. } | SubmissionVerdict::AlreadySolved => false,
+        }
+    }
+}
+
+impl LedgerCheck {
+    /// Whether the answer is known to be wrong.
+    pub fn is_known_wrong(&self) -> bool {
+        match self {
+            Self::Regression { .. }
+            | Self::KnownWrong
+            | Self::TooHigh { .. }
+            | Self::TooLow { .. } => true,
+            Self::Correct | Self::Unknown => false,
+        }
+    }
+}
+
+impl fmt::Display for LedgerCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "The answer matches the accepted answer"),
+            Self::Regression { accepted } => write!(f, "The answer differs from the accepted answer {}", accepted),
+            Self::KnownWrong => write!(f, "The answer was already rejected before"),
+            Self::TooHigh { bound } => write!(f, "The answer is too high, it has to be below {}", bound),
+            Self::TooLow { bound } => write!(f, "The answer is too low, it has to be above {}", bound),
+            Self::Unknown => write!(f, "The answer was not submitted yet"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::*;
+
+    #[test]
+    fn test_check_bounds() {
+        let mut ledger = PartLedger::default();
+        assert!(ledger.record("100", SubmissionVerdict::TooHigh));
+        assert!(ledger.record("20", SubmissionVerdict::TooLow));
+        assert!(ledger.record("50", SubmissionVerdict::Wrong));
+
+        assert_eq!(ledger.check("100"), LedgerCheck::KnownWrong);
+        assert_eq!(ledger.check("150"), LedgerCheck::TooHigh { bound: 100 });
+        assert_eq!(ledger.check("10"), LedgerCheck::TooLow { bound: 20 });
+        assert_eq!(ledger.check("50"), LedgerCheck::KnownWrong);
+        assert_eq!(ledger.check("60"), LedgerCheck::Unknown);
+        assert_eq!(ledger.check("abc"), LedgerCheck::Unknown);
+    }
+
+    #[test]
+    fn test_check_accepted() {
+        let mut ledger = PartLedger::default();
+        assert!(ledger.record("42", SubmissionVerdict::Correct));
+        assert!(!ledger.record("42", SubmissionVerdict::Correct));
+        assert!(!ledger.record("43", SubmissionVerdict::AlreadySolved));
+
+        assert_eq!(ledger.check(" 42\n"), LedgerCheck::Correct);
+        assert_eq!(ledger.check("43"), LedgerCheck::Regression { accepted: "42".to_string() });
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut ledger = AnswerLedger::default();
+        ledger.record(Part::Part1, "1", SubmissionVerdict::TooLow);
+        ledger.record(Part::Part2, "abc", SubmissionVerdict::Correct);
+
+        let json = serde_json::to_string(&ledger).unwrap();
+        let ledger: AnswerLedger = serde_json::from_str(&json).unwrap();
+        assert_eq!(ledger.check(Part::Part1, "1"), LedgerCheck::KnownWrong);
+        assert_eq!(ledger.check(Part::Part2, "abc"), LedgerCheck::Correct);
+    }
+
+    #[test]
+    fn test_check_output() {
+        struct Output {
+            answer: u64,
+        }
+        crate::impl_puzzle_result!(Output, "The answer is {}", answer);
+
+        let mut ledger = AnswerLedger::default();
+        ledger.record(Part::Part1, "42", SubmissionVerdict::Correct);
+
+        let output = |answer| SolutionOutput::new(&Output { answer }, Duration::ZERO, Duration::ZERO);
+        assert_eq!(ledger.check_output(Part::Part1, &output(42)), LedgerCheck::Correct);
+        assert_eq!(ledger.check_output(Part::Part1, &output(7)), LedgerCheck::Regression { accepted: "42".to_string() });
+        assert_eq!(ledger.check_output(Part::Part2, &output(7)), LedgerCheck::Unknown);
+    }
+}
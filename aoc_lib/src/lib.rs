@@ -1,15 +1,33 @@
 pub mod aoc_client;
+pub mod bench;
+pub mod cache;
+pub mod calendar;
+pub mod config;
+pub mod credentials;
+pub mod description;
+pub mod input_source;
 pub mod io;
+pub mod leaderboard;
+pub mod ledger;
 pub mod puzzle;
-mod util;
+pub mod schedule;
+pub mod testing;
+pub mod util;
 pub mod day;
 pub mod year;
 
 pub use aoc_client::AocClient;
 pub use puzzle::{
+    Part,
     PuzzleInput,
     PuzzleResult,
     Puzzle,
     SolutionPart1,
     SolutionPart2,
 };
+
+/// The dependencies the exported macros expand to.
+#[doc(hidden)]
+pub mod __private {
+    pub use anyhow;
+}
@@ -1,6 +1,26 @@
+use super::Example;
+use crate::schedule::{last_day, FIRST_YEAR};
+
 pub trait Puzzle: Send + Sync + 'static {
     const YEAR: u16;
     const DAY: u8;
     /// An alternative solution to the puzzle.
     const ALT: Option<&'static str> = None;
+    /// The examples from the puzzle description, used to test the solution.
+    const EXAMPLES: &'static [Example] = &[];
+}
+
+/// Fail the build if a puzzle has a year or day which doesn't exist, the solution macros of
+/// the years check every puzzle with it.
+///
+/// ```compile_fail
+/// const _: () = aoc_lib::puzzle::assert_puzzle_date(2024, 26);
+/// ```
+///
+/// ```compile_fail
+/// const _: () = aoc_lib::puzzle::assert_puzzle_date(2025, 13);
+/// ```
+pub const fn assert_puzzle_date(year: u16, day: u8) {
+    assert!(year >= FIRST_YEAR, "Advent of Code started in 2015");
+    assert!(day >= 1 && day <= last_day(year), "The day is not a day of the season");
 }
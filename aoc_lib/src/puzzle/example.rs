@@ -0,0 +1,108 @@
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use anyhow::{anyhow, Context, Result};
+
+use super::{Part, PuzzleInput, PuzzleResult};
+
+/// An example input from the puzzle description, together with the expected answers.
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    input: &'static str,
+    part1: Option<&'static str>,
+    part2: Option<&'static str>,
+}
+
+impl Example {
+    pub const fn new(input: &'static str) -> Self {
+        Self {
+            input,
+            part1: None,
+            part2: None,
+        }
+    }
+
+    /// Set the expected answer for part 1.
+    pub const fn with_part1(mut self, answer: &'static str) -> Self {
+        self.part1 = Some(answer);
+        self
+    }
+
+    /// Set the expected answer for part 2.
+    pub const fn with_part2(mut self, answer: &'static str) -> Self {
+        self.part2 = Some(answer);
+        self
+    }
+
+    pub fn input(&self) -> &'static str {
+        self.input
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::Part1 => self.part1,
+            Part::Part2 => self.part2,
+        }
+    }
+}
+
+/// An example which did not produce the expected answer.
+#[derive(Debug)]
+pub struct ExampleFailure {
+    /// The index of the example, starting at 1.
+    pub example: usize,
+    pub part: Part,
+    pub reason: ExampleFailureReason,
+}
+
+#[derive(Debug)]
+pub enum ExampleFailureReason {
+    /// The solution produced a different answer.
+    Mismatch { expected: &'static str, actual: String },
+    /// Parsing the input or solving the puzzle failed.
+    Error(anyhow::Error),
+}
+
+impl fmt::Display for ExampleFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, example {}: ", self.part, self.example)?;
+        match &self.reason {
+            ExampleFailureReason::Mismatch { expected, actual } => {
+                write!(f, "expected {}, got {}", expected, actual)
+            }
+            ExampleFailureReason::Error(err) => write!(f, "{:?}", err),
+        }
+    }
+}
+
+/// Run every example which has an expected answer for the part.
+pub(super) fn check_examples<F, I, Res>(examples: &[Example], part: Part, f: F) -> Vec<ExampleFailure>
+where
+    F: Fn(I) -> Result<Res>,
+    I: PuzzleInput,
+    Res: PuzzleResult,
+{
+    examples.iter()
+        .enumerate()
+        .filter_map(|(i, example)| example.expected(part).map(|expected| (i + 1, example, expected)))
+        .filter_map(|(i, example, expected)| {
+            let res = panic::catch_unwind(AssertUnwindSafe(|| {
+                I::from_input(example.input())
+                    .context("Failed to parse input")
+                    .and_then(|input| f(input).context("Puzzle solution failed"))
+            })).unwrap_or_else(|_| Err(anyhow!("Puzzle solution panicked")));
+
+            let reason = match res {
+                Ok(res) => {
+                    let actual = res.answer().to_string();
+                    if actual == expected {
+                        return None;
+                    }
+                    ExampleFailureReason::Mismatch { expected, actual }
+                }
+                Err(err) => ExampleFailureReason::Error(err),
+            };
+
+            Some(ExampleFailure { example: i, part, reason })
+        })
+        .collect()
+}
@@ -1,6 +1,6 @@
 use anyhow::Result;
 
-pub trait PuzzleInput: Sized {
+pub trait PuzzleInput: Sized + 'static {
     /// Indicates if the input prefers to be parsed from an owned string.
     const PREFERS_OWNED_INPUT: bool = false;
 
@@ -18,4 +18,12 @@ pub trait PuzzleInput: Sized {
     fn from_input_owned(input: String) -> Result<Self> {
         Self::from_input(&input)
     }
+
+    /// Duplicate the parsed input.
+    ///
+    /// If both parts of a day use the same input type, this allows them to share a single parse.
+    /// Returns `None` by default, in which case each part parses the input on its own.
+    fn duplicate(&self) -> Option<Self> {
+        None
+    }
 }
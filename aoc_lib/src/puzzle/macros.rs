@@ -1,22 +1,166 @@
+/// Implement `PuzzleResult` for a struct, using the format string for the message.
+///
+/// The last field is used as the answer of the puzzle.
 #[macro_export]
 macro_rules! impl_puzzle_result {
-    ($t:ty, $f:literal $(, $p:ident)*) => {
+    ($t:ty, $f:literal $(, $p:ident)+) => {
         impl $crate::puzzle::PuzzleResult for $t {
-            fn display(&self) {
-                println!($f, $(self.$p),*);
+            fn answer(&self) -> $crate::puzzle::Answer {
+                #[allow(unused_variables)]
+                let ($($p,)+) = ($(&self.$p,)+);
+                $crate::puzzle::Answer::from(::core::clone::Clone::clone($crate::__last_ident!($($p),+)))
+            }
+
+            fn message(&self) -> ::std::string::String {
+                format!($f, $(self.$p),+)
             }
         }
     };
 }
 
+/// Create a struct implementing `PuzzleResult`, see `impl_puzzle_result!`.
 #[macro_export]
 macro_rules! create_puzzle_result {
-    ($t:ident, $f:literal $(, $p:ident $(: $pty:ty)? )*) => {
+    ($t:ident, $f:literal $(, $p:ident $(: $pty:ty)? )+) => {
         #[derive(Debug)]
         pub struct $t {
-            $( $($p: $pty)? ),*
+            $( $($p: $pty)? ),+
         }
 
-        $crate::impl_puzzle_result!($t, $f $(, $p)*);
+        $crate::impl_puzzle_result!($t, $f $(, $p)+);
+    };
+}
+
+/// Declare the `PuzzleSolution` of a day with the examples of its description, in a year crate
+/// with a `const YEAR: u16` at its root.
+///
+/// The date of the puzzle is checked at compile time, see `assert_puzzle_date`.
+#[macro_export]
+#[allow(clippy::crate_in_macro_def)] // `crate::YEAR` is the year of the calling crate
+macro_rules! create_solution {
+    ($day:literal) => {
+        $crate::create_solution!($day, examples = []);
     };
+    ($day:literal, examples = [
+        $( {
+            input: $input:expr
+            $(, part1: $part1:literal)?
+            $(, part2: $part2:literal)?
+            $(,)?
+        } ),* $(,)?
+    ]) => {
+        #[doc = concat!("The solution of day ", $day, ".")]
+        pub struct PuzzleSolution;
+
+        const _: () = $crate::puzzle::assert_puzzle_date(crate::YEAR, $day);
+
+        impl $crate::puzzle::Puzzle for PuzzleSolution {
+            const DAY: u8 = $day;
+            const YEAR: u16 = crate::YEAR;
+            const EXAMPLES: &'static [$crate::puzzle::Example] = &[
+                $(
+                    $crate::puzzle::Example::new($input)
+                        $(.with_part1($part1))?
+                        $(.with_part2($part2))?
+                ),*
+            ];
+        }
+    };
+}
+
+/// Declare an alternative solution of a day, see `create_solution!`.
+///
+/// Alternative solutions share the examples of the `PuzzleSolution` of the day.
+#[macro_export]
+#[allow(clippy::crate_in_macro_def)] // `crate::YEAR` is the year of the calling crate
+macro_rules! create_alt_solution {
+    ($day:literal, $name:ident, $alt:literal) => {
+        #[doc = concat!("An alternative solution of day ", $day, ": ", $alt)]
+        pub struct $name;
+
+        const _: () = $crate::puzzle::assert_puzzle_date(crate::YEAR, $day);
+
+        impl $crate::puzzle::Puzzle for $name {
+            const DAY: u8 = $day;
+            const YEAR: u16 = crate::YEAR;
+            const ALT: Option<&'static str> = Some($alt);
+            const EXAMPLES: &'static [$crate::puzzle::Example] =
+                <PuzzleSolution as $crate::puzzle::Puzzle>::EXAMPLES;
+        }
+    }
+}
+
+/// Implement `SolutionPart1` for the `PuzzleSolution` of the day.
+#[macro_export]
+macro_rules! create_solution_part1 {
+    (($input_i:ident: $input:ty) -> $result:ty { $($code:tt)* }) => {
+        $crate::create_alt_solution_part1!(PuzzleSolution, ($input_i: $input) -> $result { $($code)* });
+    }
+}
+
+/// Implement `SolutionPart1` for an alternative solution of the day.
+#[macro_export]
+macro_rules! create_alt_solution_part1 {
+    ($name:ident, ($input_i:ident: $input:ty) -> $result:ty { $($code:tt)* }) => {
+        impl $crate::SolutionPart1 for $name {
+            type Input = $input;
+            type Result = $result;
+
+            fn solve($input_i: Self::Input) -> $crate::__private::anyhow::Result<Self::Result> {
+                $($code)*
+            }
+        }
+    }
+}
+
+/// Implement `SolutionPart2` for the `PuzzleSolution` of the day.
+#[macro_export]
+macro_rules! create_solution_part2 {
+    (($input_i:ident: $input:ty) -> $result:ty { $($code:tt)* }) => {
+        $crate::create_alt_solution_part2!(PuzzleSolution, ($input_i: $input) -> $result { $($code)* });
+    }
+}
+
+/// Implement `SolutionPart2` for an alternative solution of the day.
+#[macro_export]
+macro_rules! create_alt_solution_part2 {
+    ($name:ident, ($input_i:ident: $input:ty) -> $result:ty { $($code:tt)* }) => {
+        impl $crate::SolutionPart2 for $name {
+            type Input = $input;
+            type Result = $result;
+
+            fn solve($input_i: Self::Input) -> $crate::__private::anyhow::Result<Self::Result> {
+                $($code)*
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __last_ident {
+    ($last:ident) => { $last };
+    ($first:ident, $($rest:ident),+) => { $crate::__last_ident!($($rest),+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{Answer, PuzzleResult};
+
+    create_puzzle_result!(SingleResult, "Result: {}", value: u32);
+    create_puzzle_result!(MultiResult, "Found {} in {}", found: String, count: usize);
+
+    #[test]
+    fn test_single_field() {
+        let res = SingleResult { value: 42 };
+        assert_eq!(res.answer(), Answer::Integer(42));
+        assert_eq!(res.message(), "Result: 42");
+    }
+
+    #[test]
+    fn test_last_field_is_answer() {
+        let res = MultiResult { found: "xmas".to_string(), count: 3 };
+        assert_eq!(res.answer(), Answer::Integer(3));
+        assert_eq!(res.message(), "Found xmas in 3");
+    }
 }
@@ -3,8 +3,12 @@ mod base;
 mod solution;
 mod result;
 mod macros;
+mod part;
+mod example;
 
-pub use base::Puzzle;
+pub use base::{assert_puzzle_date, Puzzle};
+pub use example::{Example, ExampleFailure, ExampleFailureReason};
 pub use input::PuzzleInput;
-pub use solution::{SolutionPart1, SolutionPart1Ext, SolutionPart2, SolutionPart2Ext};
-pub use result::PuzzleResult;
+pub use solution::{SolutionExt, SolutionPart1, SolutionPart1Ext, SolutionPart2, SolutionPart2Ext};
+pub use result::{Answer, PuzzleResult, SolutionOutput};
+pub use part::Part;
@@ -0,0 +1,30 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Part {
+    Part1,
+    Part2,
+}
+
+impl Part {
+    /// All parts of a puzzle, in order.
+    pub const ALL: [Part; 2] = [Part::Part1, Part::Part2];
+
+    /// The level of the part, as used by the aoc answer form.
+    pub fn level(self) -> u8 {
+        match self {
+            Self::Part1 => 1,
+            Self::Part2 => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Part1 => write!(f, "Part 1"),
+            Self::Part2 => write!(f, "Part 2"),
+        }
+    }
+}
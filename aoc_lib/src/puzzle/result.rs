@@ -1,16 +1,126 @@
+use std::fmt;
+use std::time::Duration;
+
 pub trait PuzzleResult {
-    /// Display the result.
-    fn display(&self);
+    /// The canonical answer of the puzzle, as it would be submitted.
+    fn answer(&self) -> Answer;
+
+    /// A human-readable description of the result.
+    fn message(&self) -> String;
 }
 
-impl PuzzleResult for () {
-    fn display(&self) {
-        println!("No result to display");
+impl PuzzleResult for String {
+    fn answer(&self) -> Answer {
+        Answer::Text(self.clone())
+    }
+
+    fn message(&self) -> String {
+        self.clone()
     }
 }
 
-impl PuzzleResult for String {
-    fn display(&self) {
-        println!("{}", self);
+/// The answer to a puzzle part.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(value) => value.fmt(f),
+            Self::Text(value) => value.fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Self::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+/// The outcome of running a solution part.
+#[derive(Debug, Clone)]
+pub struct SolutionOutput {
+    answer: Answer,
+    message: String,
+    parse_time: Duration,
+    solve_time: Duration,
+    shared_parse: bool,
+}
+
+impl SolutionOutput {
+    pub fn new<R: PuzzleResult + ?Sized>(result: &R, parse_time: Duration, solve_time: Duration) -> Self {
+        Self {
+            answer: result.answer(),
+            message: result.message(),
+            parse_time,
+            solve_time,
+            shared_parse: false,
+        }
+    }
+
+    /// Mark the input as parsed by the other part of the day, no parse time is attributed to
+    /// this part.
+    pub fn with_shared_parse(mut self) -> Self {
+        self.parse_time = Duration::ZERO;
+        self.shared_parse = true;
+        self
+    }
+
+    pub fn answer(&self) -> &Answer {
+        &self.answer
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The time it took to parse the input.
+    pub fn parse_time(&self) -> Duration {
+        self.parse_time
+    }
+
+    /// The time it took to solve the puzzle, excluding the parsing.
+    pub fn solve_time(&self) -> Duration {
+        self.solve_time
+    }
+
+    /// Whether the input was parsed by the other part of the day.
+    pub fn shared_parse(&self) -> bool {
+        self.shared_parse
+    }
+
+    /// The time it took to parse the input and solve the puzzle.
+    pub fn elapsed(&self) -> Duration {
+        self.parse_time + self.solve_time
     }
 }
@@ -1,7 +1,11 @@
+use std::any::{Any, TypeId};
 use std::hint::black_box;
+use std::time::{Duration, Instant};
 use anyhow::{Context, Result};
-use crate::util::duration::DisplayDuration;
-use super::{Puzzle, PuzzleInput, PuzzleResult};
+use crate::bench::{self, BenchConfig, PhaseReport};
+use crate::input_source::InputSource;
+use super::{ExampleFailure, Part, Puzzle, PuzzleInput, PuzzleResult, SolutionOutput};
+use super::example::check_examples;
 
 pub trait SolutionPart1: Puzzle {
     type Input: PuzzleInput;
@@ -19,112 +23,205 @@ pub trait SolutionPart2: Puzzle {
     fn solve(input: Self::Input) -> Result<Self::Result>;
 }
 
-async fn run_solution<F, I, Res>(year: u16, day: u8, client: &crate::AocClient, f: F) -> Result<()>
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let now = Instant::now();
+    let res = f();
+    (res, now.elapsed())
+}
+
+/// Convert a value to another type, if it is in fact the same type.
+fn cast<From: 'static, To: 'static>(value: From) -> Option<To> {
+    (Box::new(value) as Box<dyn Any>).downcast()
+        .ok()
+        .map(|value| *value)
+}
+
+fn solve_timed<F, I, Res>(input: I, parse_time: Duration, f: F) -> Result<SolutionOutput>
+where
+    F: FnOnce(I) -> Result<Res>,
+    Res: PuzzleResult,
+{
+    let (res, solve_time) = timed(|| f(input));
+    let res = res.context("Puzzle solution failed")?;
+
+    Ok(SolutionOutput::new(&res, parse_time, solve_time))
+}
+
+fn run_with_input<F, I, Res>(input: String, f: F) -> Result<SolutionOutput>
+where
+    F: FnOnce(I) -> Result<Res>,
+    I: PuzzleInput,
+    Res: PuzzleResult,
+{
+    let (input, parse_time) = timed(|| I::from_input_owned(input));
+    let input = input.context("Failed to parse input")?;
+
+    solve_timed(input, parse_time, f)
+}
+
+async fn run_solution<F, I, Res>(year: u16, day: u8, source: &dyn InputSource, f: F) -> Result<SolutionOutput>
 where
     F: FnOnce(I) -> Result<Res>,
     I: PuzzleInput,
     Res: PuzzleResult,
 {
-    let input = client.get_challenge(year, day)
+    let input = source.get_input(year, day)
         .await
         .context("Failed to fetch input")?;
 
-    let input = PuzzleInput::from_input_owned(input)
-        .context("Failed to parse input")?;
+    run_with_input(input, f)
+}
 
-    let res = f(input)
-        .context("Puzzle solution failed")?;
+/// Run both parts, sharing a single parse if both use the same input type and it can be
+/// duplicated.
+///
+/// The outer error is returned if the input could not be fetched, or if the shared parse failed.
+async fn run_both<P>(source: &dyn InputSource) -> Result<[Result<SolutionOutput>; 2]>
+where
+    P: SolutionPart1 + SolutionPart2,
+{
+    let input = source.get_input(P::YEAR, P::DAY)
+        .await
+        .context("Failed to fetch input")?;
+
+    if TypeId::of::<<P as SolutionPart1>::Input>() != TypeId::of::<<P as SolutionPart2>::Input>() {
+        return Ok([
+            run_with_input(input.clone(), <P as SolutionPart1>::solve),
+            run_with_input(input, <P as SolutionPart2>::solve),
+        ]);
+    }
+
+    let owned = input.clone();
+    let (parsed, parse_time) = timed(|| <P as SolutionPart1>::Input::from_input_owned(owned));
+    let parsed = parsed.context("Failed to parse input")?;
+
+    let part2 = match parsed.duplicate().and_then(cast) {
+        Some(copy) => solve_timed(copy, Duration::ZERO, <P as SolutionPart2>::solve)
+            .map(SolutionOutput::with_shared_parse),
+        None => run_with_input(input, <P as SolutionPart2>::solve),
+    };
+    let part1 = solve_timed(parsed, parse_time, <P as SolutionPart1>::solve);
+
+    Ok([part1, part2])
+}
 
-    res.display();
+/// Parse the input the way the input type prefers, returning the time it took.
+fn parse_timed<I: PuzzleInput>(input: &str) -> Result<(I, Duration)> {
+    let (parsed, parse_time) = if I::PREFERS_OWNED_INPUT {
+        // Clone outside of the measurement, so only the parsing is measured
+        let input = input.to_string();
+        timed(|| black_box(I::from_input_owned(black_box(input))))
+    } else {
+        timed(|| black_box(I::from_input(black_box(input))))
+    };
 
-    Ok(())
+    parsed.context("Failed to parse input")
+        .map(|parsed| (parsed, parse_time))
 }
 
-fn benchmark_inner<F, I, Res>(input: &str, f: F) -> Result<()>
+fn solve_time<F, I, Res>(input: I, f: F) -> Result<Duration>
 where
     F: FnOnce(I) -> Result<Res>,
-    I: PuzzleInput,
     Res: PuzzleResult,
 {
-    let input = PuzzleInput::from_input(input)
-        .context("Failed to parse input")?;
+    let (res, solve_time) = timed(|| black_box(f(black_box(input))));
+    res.context("Puzzle solution failed")?;
 
-    let _ = f(input)
-        .context("Puzzle solution failed")?;
-
-    Ok(())
+    Ok(solve_time)
 }
 
-fn benchmark_inner_owned<F, I, Res>(input: String, f: F) -> Result<()>
+fn benchmark_with_input<F, I, Res>(input: &str, config: &BenchConfig, f: F) -> Result<PhaseReport>
 where
-    F: FnOnce(I) -> Result<Res>,
+    F: Fn(I) -> Result<Res>,
     I: PuzzleInput,
     Res: PuzzleResult,
 {
-    let input = PuzzleInput::from_input_owned(input)
-        .context("Failed to parse input")?;
+    let mut iteration = 0;
+    let [parse, solve] = bench::run(config, || {
+        iteration += 1;
 
-    let _ = f(input)
-        .context("Puzzle solution failed")?;
+        parse_timed(input)
+            .and_then(|(parsed, parse_time)| Ok([parse_time, solve_time(parsed, &f)?]))
+            .with_context(|| format!("Benchmark failed on the {}. iteration", iteration))
+    })?;
 
-    Ok(())
+    Ok(PhaseReport::new(parse, solve))
 }
 
-async fn benchmark_solution<F, I, Res, const PART2: bool>(year: u16, day: u8, client: &crate::AocClient, iterations: u32, mut f: F) -> Result<()>
+async fn benchmark_solution<F, I, Res>(year: u16, day: u8, source: &dyn InputSource, config: &BenchConfig, f: F) -> Result<PhaseReport>
 where
-    F: FnMut(I) -> Result<Res>,
+    F: Fn(I) -> Result<Res>,
     I: PuzzleInput,
     Res: PuzzleResult,
 {
-    let input = client.get_challenge(year, day).await
+    let input = source.get_input(year, day).await
         .context("Failed to fetch input")?;
 
-    if I::PREFERS_OWNED_INPUT {
-        let input_clones = (0..iterations)
-            .map(|_| input.clone())
-            .collect::<Vec<_>>();
-
-        let now = std::time::Instant::now();
-        for (i, input) in input_clones.into_iter().enumerate() {
-            black_box(benchmark_inner_owned(input, &mut f))
-                .with_context(|| format!("Benchmark failed on the {}. iteration", i))?;
-        }
-        let elapsed = now.elapsed();
-        let dur = elapsed / iterations;
-
-        if PART2 {
-            println!("Part 2: Average time: {}", DisplayDuration(dur));
-        } else {
-            println!("Part 1: Average time: {}", DisplayDuration(dur));
-        }
-    } else {
-        let now = std::time::Instant::now();
-        for i in 0..iterations {
-            black_box(benchmark_inner(&input, &mut f))
-                .with_context(|| format!("Benchmark failed on the {}. iteration", i))?;
-        }
-        let elapsed = now.elapsed();
-        let dur = elapsed / iterations;
-
-        if PART2 {
-            println!("Part 2: Average time: {}", DisplayDuration(dur));
-        } else {
-            println!("Part 1: Average time: {}", DisplayDuration(dur));
-        }
+    benchmark_with_input(&input, config, f)
+}
+
+/// Benchmark both parts, sharing a single parse if both use the same input type and it can be
+/// duplicated.
+async fn benchmark_both<P>(source: &dyn InputSource, config: &BenchConfig) -> Result<[PhaseReport; 2]>
+where
+    P: SolutionPart1 + SolutionPart2,
+{
+    let input = source.get_input(P::YEAR, P::DAY).await
+        .context("Failed to fetch input")?;
+
+    let same_input = TypeId::of::<<P as SolutionPart1>::Input>() == TypeId::of::<<P as SolutionPart2>::Input>();
+    let shareable = same_input && parse_timed::<<P as SolutionPart1>::Input>(&input)?.0
+        .duplicate()
+        .is_some();
+
+    if !shareable {
+        return Ok([
+            benchmark_with_input(&input, config, <P as SolutionPart1>::solve)?,
+            benchmark_with_input(&input, config, <P as SolutionPart2>::solve)?,
+        ]);
     }
 
-    Ok(())
+    let mut iteration = 0;
+    let [parse, solve1, solve2] = bench::run(config, || {
+        iteration += 1;
+
+        let res = parse_timed::<<P as SolutionPart1>::Input>(&input)
+            .and_then(|(parsed, parse_time)| {
+                // Duplicating is not part of the measurement, it is only needed to run both parts
+                let copy: <P as SolutionPart2>::Input = parsed.duplicate()
+                    .and_then(cast)
+                    .expect("The input types should be the same and duplicable");
+
+                Ok([
+                    parse_time,
+                    solve_time(parsed, <P as SolutionPart1>::solve)?,
+                    solve_time(copy, <P as SolutionPart2>::solve)?,
+                ])
+            });
+
+        res.with_context(|| format!("Benchmark failed on the {}. iteration", iteration))
+    })?;
+
+    Ok([
+        PhaseReport::new(parse.clone(), solve1).with_shared_parse(),
+        PhaseReport::new(parse, solve2).with_shared_parse(),
+    ])
 }
 
 pub trait SolutionPart1Ext: SolutionPart1 + sealed::SealedPart1 {
     /// Run the Part 1 of the puzzle.
-    fn run_part1<'a>(client: &'a crate::AocClient) -> impl std::future::Future<Output = Result<()>> + Send + Sync + 'a {
-        run_solution(Self::YEAR, Self::DAY, client, Self::solve)
+    fn run_part1<'a>(source: &'a dyn InputSource) -> impl std::future::Future<Output = Result<SolutionOutput>> + Send + 'a {
+        run_solution(Self::YEAR, Self::DAY, source, <Self as SolutionPart1>::solve)
     }
 
     /// Benchmark the Part 1 of the puzzle.
-    fn bench_part1<'a>(client: &'a crate::AocClient, iterations: u32) -> impl std::future::Future<Output = Result<()>> + Send + Sync + 'a {
-        benchmark_solution::<_,_,_,false>(Self::YEAR, Self::DAY, client, iterations, Self::solve)
+    fn bench_part1<'a>(source: &'a dyn InputSource, config: &'a BenchConfig) -> impl std::future::Future<Output = Result<PhaseReport>> + Send + 'a {
+        benchmark_solution(Self::YEAR, Self::DAY, source, config, <Self as SolutionPart1>::solve)
+    }
+
+    /// Check the Part 1 of the puzzle against the examples.
+    fn check_part1_examples() -> Vec<ExampleFailure> {
+        check_examples(Self::EXAMPLES, Part::Part1, <Self as SolutionPart1>::solve)
     }
 }
 
@@ -133,20 +230,53 @@ impl<T> sealed::SealedPart1 for T where T: SolutionPart1 {}
 
 pub trait SolutionPart2Ext: SolutionPart2 + sealed::SealedPart2 {
     /// Run the Part 2 of the puzzle.
-    fn run_part2<'a>(client: &'a crate::AocClient) -> impl std::future::Future<Output = Result<()>> + Send + Sync + 'a {
-        run_solution(Self::YEAR, Self::DAY, client, Self::solve)
+    fn run_part2<'a>(source: &'a dyn InputSource) -> impl std::future::Future<Output = Result<SolutionOutput>> + Send + 'a {
+        run_solution(Self::YEAR, Self::DAY, source, <Self as SolutionPart2>::solve)
     }
 
     /// Benchmark the Part 2 of the puzzle.
-    fn bench_part2<'a>(client: &'a crate::AocClient, iterations: u32) -> impl std::future::Future<Output = Result<()>> + Send + Sync + 'a {
-        benchmark_solution::<_,_,_,true>(Self::YEAR, Self::DAY, client, iterations, Self::solve)
+    fn bench_part2<'a>(source: &'a dyn InputSource, config: &'a BenchConfig) -> impl std::future::Future<Output = Result<PhaseReport>> + Send + 'a {
+        benchmark_solution(Self::YEAR, Self::DAY, source, config, <Self as SolutionPart2>::solve)
+    }
+
+    /// Check the Part 2 of the puzzle against the examples.
+    fn check_part2_examples() -> Vec<ExampleFailure> {
+        check_examples(Self::EXAMPLES, Part::Part2, <Self as SolutionPart2>::solve)
     }
 }
 
 impl<T> SolutionPart2Ext for T where T: SolutionPart2 {}
 impl<T> sealed::SealedPart2 for T where T: SolutionPart2 {}
 
+pub trait SolutionExt: SolutionPart1 + SolutionPart2 + sealed::Sealed + Sized {
+    /// Run both parts of the puzzle, see `PuzzleInput::duplicate` on sharing the parsed input.
+    fn run_both(source: &dyn InputSource) -> impl std::future::Future<Output = Result<[Result<SolutionOutput>; 2]>> + Send + '_ {
+        run_both::<Self>(source)
+    }
+
+    /// Benchmark both parts of the puzzle, see `PuzzleInput::duplicate` on sharing the parsed
+    /// input.
+    fn bench_both<'a>(source: &'a dyn InputSource, config: &'a BenchConfig) -> impl std::future::Future<Output = Result<[PhaseReport; 2]>> + Send + 'a {
+        benchmark_both::<Self>(source, config)
+    }
+}
+
+impl<T> SolutionExt for T where T: SolutionPart1 + SolutionPart2 {}
+impl<T> sealed::Sealed for T where T: SolutionPart1 + SolutionPart2 {}
+
 mod sealed {
     pub trait SealedPart1 {}
     pub trait SealedPart2 {}
+    pub trait Sealed {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cast() {
+        assert_eq!(cast::<_, String>("abc".to_string()), Some("abc".to_string()));
+        assert_eq!(cast::<_, String>(42u32), None);
+    }
 }
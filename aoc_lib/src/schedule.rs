@@ -0,0 +1,94 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+
+/// The puzzles unlock at midnight in the US Eastern time zone, which is always EST in December.
+const UNLOCK_OFFSET_SECS: i32 = -5 * 60 * 60;
+
+/// The year of the first season.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// The last day of the longest seasons.
+pub const MAX_DAY: u8 = 25;
+
+/// The first season with only 12 days.
+const SHORT_SEASONS_SINCE: u16 = 2025;
+
+/// The last day of the season of the year, which has only one part to solve.
+pub const fn last_day(year: u16) -> u8 {
+    if year >= SHORT_SEASONS_SINCE {
+        12
+    } else {
+        MAX_DAY
+    }
+}
+
+/// The US Eastern time zone as it is during the season.
+pub fn eastern() -> FixedOffset {
+    FixedOffset::east_opt(UNLOCK_OFFSET_SECS).expect("The offset is in range")
+}
+
+/// The moment the puzzle of the given day unlocks, `None` if there is no such puzzle.
+pub fn unlock_time(year: u16, day: u8) -> Option<DateTime<Utc>> {
+    if year < FIRST_YEAR || !(1..=last_day(year)).contains(&day) {
+        return None;
+    }
+
+    let date = NaiveDate::from_ymd_opt(year as i32, 12, day as u32)?;
+    let unlock = eastern().from_local_datetime(&date.and_hms_opt(0, 0, 0)?).single()?;
+
+    Some(unlock.with_timezone(&Utc))
+}
+
+/// Whether the puzzle of the given day was unlocked at the given time.
+pub fn is_unlocked(year: u16, day: u8, now: DateTime<Utc>) -> bool {
+    unlock_time(year, day).is_some_and(|unlock| now >= unlock)
+}
+
+/// The time left until the puzzle unlocks, `None` if it is already unlocked or doesn't exist.
+pub fn time_until_unlock(year: u16, day: u8, now: DateTime<Utc>) -> Option<Duration> {
+    unlock_time(year, day)
+        .map(|unlock| unlock - now)
+        .filter(|left| *left > Duration::zero())
+}
+
+/// The next puzzle to unlock after the given time, as year, day and unlock time.
+pub fn next_unlock(now: DateTime<Utc>) -> (u16, u8, DateTime<Utc>) {
+    let year = now.with_timezone(&eastern()).year().max(FIRST_YEAR as i32) as u16;
+
+    (1..=last_day(year))
+        .filter_map(|day| unlock_time(year, day).map(|unlock| (year, day, unlock)))
+        .find(|&(_, _, unlock)| unlock > now)
+        .unwrap_or_else(|| (year + 1, 1, unlock_time(year + 1, 1).expect("Day 1 always exists")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlock_time() {
+        let unlock = unlock_time(2024, 3).unwrap();
+        assert_eq!(unlock, Utc.with_ymd_and_hms(2024, 12, 3, 5, 0, 0).unwrap());
+
+        assert!(!is_unlocked(2024, 3, unlock - Duration::seconds(1)));
+        assert!(is_unlocked(2024, 3, unlock));
+        assert_eq!(time_until_unlock(2024, 3, unlock - Duration::minutes(5)), Some(Duration::minutes(5)));
+        assert_eq!(time_until_unlock(2024, 3, unlock), None);
+
+        assert_eq!(unlock_time(2024, 26), None);
+        assert_eq!(unlock_time(2014, 1), None);
+        assert!(unlock_time(2025, 12).is_some());
+        assert_eq!(unlock_time(2025, 13), None);
+    }
+
+    #[test]
+    fn test_next_unlock() {
+        let unlock = unlock_time(2024, 3).unwrap();
+        assert_eq!(next_unlock(unlock - Duration::seconds(1)), (2024, 3, unlock));
+        assert_eq!(next_unlock(unlock).1, 4);
+
+        let summer = Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap();
+        assert_eq!(next_unlock(summer), (2024, 1, unlock_time(2024, 1).unwrap()));
+        assert_eq!(next_unlock(unlock_time(2024, 25).unwrap()), (2025, 1, unlock_time(2025, 1).unwrap()));
+        assert_eq!(next_unlock(unlock_time(2025, 12).unwrap()), (2026, 1, unlock_time(2026, 1).unwrap()));
+    }
+}
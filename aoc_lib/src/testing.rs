@@ -0,0 +1,41 @@
+//! Helpers to test the solutions of a year from within `cargo test`.
+
+use std::fmt::Write;
+
+use crate::day::Day;
+use crate::puzzle::ExampleFailure;
+use crate::year::Year;
+
+/// Check every solution of the year against the examples of its puzzle.
+pub fn check_examples(year: &Year) -> Vec<(&Day, ExampleFailure)> {
+    year.get_all_days()
+        .flat_map(|day| {
+            day.check_examples()
+                .into_iter()
+                .map(move |failure| (day, failure))
+        })
+        .collect()
+}
+
+/// Check every solution of the year against the examples of its puzzle.
+///
+/// # Panics
+/// This function panics with a report of all failures if any example does not produce the
+/// expected answer.
+pub fn assert_examples(year: &Year) {
+    let failures = check_examples(year);
+    if failures.is_empty() {
+        return;
+    }
+
+    let mut report = format!("{} example(s) failed:\n", failures.len());
+    for (day, failure) in failures {
+        write!(report, "  {} day {}", day.year(), day.day()).unwrap();
+        if let Some(alt) = day.alt() {
+            write!(report, " ({})", alt).unwrap();
+        }
+        writeln!(report, " {}", failure).unwrap();
+    }
+
+    panic!("{}", report);
+}
@@ -4,6 +4,21 @@ use std::time::Duration;
 #[derive(Debug, Clone, Copy)]
 pub struct DisplayDuration(pub Duration);
 
+/// Displays a duration as a countdown clock, e.g. `1d 02:03:04`.
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayCountdown(pub Duration);
+
+impl fmt::Display for DisplayCountdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let secs = self.0.as_secs();
+        let (days, hours, minutes, secs) = (secs / 86400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+        if days > 0 {
+            write!(f, "{}d ", days)?;
+        }
+        write!(f, "{:02}:{:02}:{:02}", hours, minutes, secs)
+    }
+}
+
 impl fmt::Display for DisplayDuration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let secs = self.0.as_secs();
@@ -1,14 +1,12 @@
 use std::collections::HashMap;
-use seq_macro::seq;
 
 use crate::day::Day;
 
-seq!(N in 1..=25 {
-    #[derive(Debug)]
-    pub(super) struct DayMap {
-        #( day~N: DayMapValue, )*
-    }
-});
+/// The days of a year, by their number starting at 1.
+#[derive(Debug)]
+pub(super) struct DayMap {
+    days: Vec<DayMapValue>,
+}
 
 #[derive(Debug)]
 pub(super) struct DayMapValue {
@@ -16,45 +14,36 @@ pub(super) struct DayMapValue {
     pub(super) alternatives: Option<HashMap<&'static str, Day>>,
 }
 
-seq!(N in 1..=25 {
-    impl DayMap {
-        /// Creates a new `DayMap` for the given year.
-        pub(super) fn new(year: u16) -> Self {
-            Self {
-                #( day~N: DayMapValue::new(year, N), )*
-            }
+impl DayMap {
+    /// Creates a new `DayMap` for the given year with days 1 to `day_count`.
+    pub(super) fn new(year: u16, day_count: u8) -> Self {
+        Self {
+            days: (1..=day_count).map(|day| DayMapValue::new(year, day)).collect(),
         }
+    }
 
-        /// Returns the day for the given number.
-        ///
-        /// Panics if the day is not a valid aoc day (1-25).
-        pub(super) fn get(&self, day: u8) -> &DayMapValue {
-            match day {
-                #(
-                    N => &self.day~N,
-                )*
-                _ => panic!("Invalid day number: {}", day),
-            }
-        }
+    /// Returns the day for the given number, `None` if the year has no such day.
+    pub(super) fn get(&self, day: u8) -> Option<&DayMapValue> {
+        self.days.get(usize::from(day).checked_sub(1)?)
+    }
 
-        /// Sets the day for the given number.
-        ///
-        /// Note: if the day already exists, it will be replaced.
-        pub(super) fn set(&mut self, value: Day) {
-            match value.day() {
-                #(
-                    N => self.day~N.insert(value),
-                )*
-                _ => {}
-            }
-        }
+    /// Sets the day for the given number, the day is handed back if the year has no such day.
+    ///
+    /// Note: if the day already exists, it will be replaced.
+    pub(super) fn set(&mut self, value: Day) -> Result<(), Day> {
+        let Some(entry) = usize::from(value.day()).checked_sub(1).and_then(|i| self.days.get_mut(i)) else {
+            return Err(value);
+        };
 
-        /// Returns an iterator over all days.
-        pub(super) fn days(&self) -> impl ExactSizeIterator<Item = &DayMapValue> {
-            [ #( &self.day~N,)* ].into_iter()
-        }
+        entry.insert(value);
+        Ok(())
     }
-});
+
+    /// Returns an iterator over all days.
+    pub(super) fn days(&self) -> impl ExactSizeIterator<Item = &DayMapValue> {
+        self.days.iter()
+    }
+}
 
 impl DayMapValue {
     fn new(year: u16, day: u8) -> Self {
@@ -67,11 +56,25 @@ impl DayMapValue {
     fn insert(&mut self, value: Day) {
         if let Some(key) = value.alt() {
             self.insert_into_option_map(key, value.clone());
+
+            // Alternatives only stand in for the default if there is no solution yet
+            if !self.default_day.is_unsolved() && self.default_day.alt().is_none() {
+                return;
+            }
         }
 
         self.default_day = value;
     }
 
+    /// Returns the default day followed by all alternatives, without duplicates.
+    pub(super) fn all(&self) -> impl Iterator<Item = &Day> {
+        let default = Some(&self.default_day)
+            .filter(|day| day.alt().is_none());
+
+        default.into_iter()
+            .chain(self.alternatives.iter().flat_map(|map| map.values()))
+    }
+
     fn insert_into_option_map(&mut self, key: &'static str, value: Day) {
         if let Some(map) = &mut self.alternatives {
             map.insert(key, value);
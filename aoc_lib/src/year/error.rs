@@ -0,0 +1,23 @@
+use std::fmt;
+
+/// A day which can't be added to or looked up in a year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidDay {
+    /// The day number is not a day of the season.
+    OutOfRange { year: u16, day: u8 },
+    /// The solution is for a puzzle of another year.
+    WrongYear { year: u16, day: u8, puzzle_year: u16 },
+}
+
+impl fmt::Display for InvalidDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange { year, day } => write!(f, "There is no day {} in {}", day, year),
+            Self::WrongYear { year, day, puzzle_year } => {
+                write!(f, "The solution of {} day {} can't be added to {}", puzzle_year, day, year)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvalidDay {}
@@ -1,6 +1,8 @@
 mod year;
 mod years;
 mod day_map;
+mod error;
 
+pub use error::InvalidDay;
 pub use year::Year;
-pub use years::Years;
+pub use years::{current_aoc_season, Years};
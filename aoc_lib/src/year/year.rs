@@ -1,56 +1,81 @@
 use std::collections::HashMap;
 use crate::day::{Day, PartialDay};
 use crate::day::solved::SolvedDayBox;
-use super::day_map::DayMap;
+use super::day_map::{DayMap, DayMapValue};
+use super::InvalidDay;
+use crate::schedule;
+
+/// The alternative solutions of a day by their name.
+type Alternatives = HashMap<&'static str, Day>;
 
 #[derive(Debug)]
 pub struct Year {
     year: u16,
+    day_count: u8,
     days: DayMap,
 }
 
 impl Year {
-    /// Create a new year with the given year number.
+    /// Create a new year with the given year number, with as many days as its season.
+    ///
+    /// The length of the season comes from [`schedule::last_day`], which the solution macros
+    /// check the puzzles against as well.
     pub fn new(year: u16) -> Self {
-        Self {
-            year,
-            days: DayMap::new(year),
-        }
+        Self::with_day_count(year, schedule::last_day(year))
     }
 
-    /// Add a task day to the year.
+    /// Create a new year with days 1 to `day_count`, e.g. for a season which was announced with
+    /// a different length.
+    ///
+    /// Puzzles are still checked against [`schedule::last_day`] at compile time, so a longer
+    /// season needs `last_day` updated as well.
     ///
     /// # Panics
-    /// This function will panic if the day's year does not match the year of this year.
-    pub fn add_day(&mut self, day: Day) {
-        assert_eq!(day.year(), self.year);
-        self.days.set(day);
+    /// Panics if `day_count` is 0 or more than [`schedule::MAX_DAY`].
+    pub fn with_day_count(year: u16, day_count: u8) -> Self {
+        assert!(
+            (1..=schedule::MAX_DAY).contains(&day_count),
+            "A season has 1 to {} days, not {}", schedule::MAX_DAY, day_count
+        );
+
+        Self {
+            year,
+            day_count,
+            days: DayMap::new(year, day_count),
+        }
     }
 
-    /// Add multiple task days to the year.
+    /// Add a task day to the year, a partially solved last day becomes the final day.
     ///
-    /// # Panics
-    /// This function will panic if any of the days' year does not match the year of this year.
-    pub fn add_days(&mut self, days: impl IntoIterator<Item = Day>) {
-        for day in days {
-            self.add_day(day);
+    /// Fails if the day's year does not match the year of this year, or its number is not a
+    /// day of the year.
+    pub fn add_day(&mut self, day: Day) -> Result<(), InvalidDay> {
+        if day.year() != self.year {
+            return Err(InvalidDay::WrongYear { year: self.year, day: day.day(), puzzle_year: day.year() });
         }
+        let day = if day.day() == self.day_count { day.into_final() } else { day };
+
+        self.days.set(day)
+            .map_err(|day| InvalidDay::OutOfRange { year: self.year, day: day.day() })
     }
 
-    /// Get the day with the given number.
-    ///
-    /// Panics if the day is not a valid aoc day (ie not in the range 1..=25).
-    pub fn get_day(&self, day: u8) -> &Day {
-        &self.days.get(day).default_day
+    /// Add multiple task days to the year, stopping at the first invalid day.
+    pub fn add_days(&mut self, days: impl IntoIterator<Item = Day>) -> Result<(), InvalidDay> {
+        days.into_iter().try_for_each(|day| self.add_day(day))
+    }
+
+    /// Get the day with the given number, fails if it is not a day of the year.
+    pub fn get_day(&self, day: u8) -> Result<&Day, InvalidDay> {
+        Ok(&self.entry(day)?.default_day)
     }
 
-    pub fn get_day_full(&self, day: u8) -> (&Day, Option<&HashMap<&'static str, Day>>) {
-        let day = self.days.get(day);
-        (&day.default_day, day.alternatives.as_ref())
+    pub fn get_day_full(&self, day: u8) -> Result<(&Day, Option<&Alternatives>), InvalidDay> {
+        let day = self.entry(day)?;
+        Ok((&day.default_day, day.alternatives.as_ref()))
     }
 
-    pub fn get_alternatives_for(&self, day: u8) -> Option<&HashMap<&'static str, Day>> {
-        self.days.get(day).alternatives.as_ref()
+    pub fn get_alternatives_for(&self, day: u8) -> Result<Option<&Alternatives>, InvalidDay> {
+        Ok(self.entry(day)?.alternatives.as_ref())
     }
 
     pub fn get_partial(&self) -> impl Iterator<Item = PartialDay> + '_ {
@@ -59,6 +84,12 @@ impl Year {
             .filter_map(Day::try_into_partial)
     }
 
+    /// Get every day of the year, including all alternative solutions.
+    pub fn get_all_days(&self) -> impl Iterator<Item = &Day> + '_ {
+        self.days.days()
+            .flat_map(|d| d.all())
+    }
+
     pub fn get_solved(&self) -> impl Iterator<Item = SolvedDayBox> + '_ {
         self.days.days()
             .map(|d| &d.default_day)
@@ -68,4 +99,48 @@ impl Year {
     pub fn year(&self) -> u16 {
         self.year
     }
+
+    /// The number of days of the year, the last of them is the final day.
+    pub fn day_count(&self) -> u8 {
+        self.day_count
+    }
+
+    fn entry(&self, day: u8) -> Result<&DayMapValue, InvalidDay> {
+        self.days.get(day)
+            .ok_or(InvalidDay::OutOfRange { year: self.year, day })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_days() {
+        let mut year = Year::new(2024);
+        assert!(year.add_day(Day::unsolved(2024, 25)).is_ok());
+        assert_eq!(
+            year.add_day(Day::unsolved(2023, 1)),
+            Err(InvalidDay::WrongYear { year: 2024, day: 1, puzzle_year: 2023 })
+        );
+        assert_eq!(year.add_day(Day::unsolved(2024, 26)), Err(InvalidDay::OutOfRange { year: 2024, day: 26 }));
+        assert!(year.get_day(0).is_err());
+        assert!(year.get_day(1).is_ok_and(Day::is_unsolved));
+    }
+
+    #[test]
+    fn test_day_count() {
+        assert_eq!(Year::new(2024).day_count(), 25);
+        assert_eq!(Year::new(2025).day_count(), 12);
+
+        let mut year = Year::with_day_count(2025, 3);
+        assert!(year.add_day(Day::unsolved(2025, 4)).is_err());
+        assert_eq!(year.get_all_days().count(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_day_count_above_max() {
+        Year::with_day_count(2024, schedule::MAX_DAY + 1);
+    }
 }
@@ -0,0 +1,285 @@
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_lib::bench::{git_revision, BenchComparison, BenchConfig, BenchRecord, ChangeVerdict, PhaseChange, PhaseReport};
+use aoc_lib::day::PartialDay;
+use aoc_lib::year::Years;
+use aoc_lib::{io, AocClient, Part};
+use serde::Serialize;
+
+use crate::output::{csv_field, OutputFormat, Record, RecordWriter};
+use crate::run::{all_partial_days, describe_day};
+
+/// The run benchmark results are compared against.
+#[derive(Debug, Clone)]
+pub enum CompareTo {
+    /// The latest run of the same part.
+    Previous,
+    /// The latest run of the same part which was saved as the named baseline.
+    Baseline(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchOptions {
+    config: BenchConfig,
+    compare: Option<CompareTo>,
+    save_baseline: Option<String>,
+}
+
+impl BenchOptions {
+    pub fn new(config: BenchConfig) -> Self {
+        Self {
+            config,
+            compare: None,
+            save_baseline: None,
+        }
+    }
+
+    /// Compare the results against an earlier run.
+    pub fn with_compare(mut self, compare: CompareTo) -> Self {
+        self.compare = Some(compare);
+        self
+    }
+
+    /// Save the results as the named baseline.
+    pub fn with_save_baseline(mut self, name: String) -> Self {
+        self.save_baseline = Some(name);
+        self
+    }
+}
+
+/// The benchmark results of a single part.
+pub struct BenchedPart {
+    part: Part,
+    report: PhaseReport,
+    /// The comparison against an earlier run, if one was requested and there is one.
+    comparison: Option<BenchComparison>,
+}
+
+/// Benchmark every solved part of the day and record the results in the benchmark history.
+///
+/// A benchmark history which can't be loaded or saved is only warned about, the results are
+/// still returned.
+pub async fn bench_day(day: &PartialDay, client: &AocClient, options: &BenchOptions) -> Result<Vec<BenchedPart>> {
+    let mut history = client.get_bench_history(day.year(), day.day())
+        .map_err(|err| io::print_warning(format_args!("{:?}", err.context("Failed to load the benchmark history"))))
+        .ok();
+    let reports = day.bench_parts(client, &options.config).await?;
+    let revision = git_revision();
+
+    let mut parts = Vec::new();
+    for (part, report) in reports {
+        let mut record = BenchRecord::new(&report, day.alt(), part, revision.clone());
+        if let Some(name) = &options.save_baseline {
+            record = record.with_baseline(name.clone());
+        }
+
+        let Some(history) = &mut history else {
+            parts.push(BenchedPart { part, report, comparison: None });
+            continue;
+        };
+
+        let previous = match &options.compare {
+            None => None,
+            Some(CompareTo::Previous) => history.previous(day.alt(), part),
+            Some(CompareTo::Baseline(name)) => history.baseline(name, day.alt(), part),
+        };
+        let comparison = previous.map(|previous| record.compare(previous));
+
+        history.record(record);
+        parts.push(BenchedPart { part, report, comparison });
+    }
+
+    if let Some(history) = history {
+        if let Err(err) = client.save_bench_history(day.year(), day.day(), &history) {
+            io::print_warning(format_args!("{:?}", err.context("Failed to save the benchmark history")));
+        }
+    }
+
+    Ok(parts)
+}
+
+/// Print the benchmark results of a day for humans.
+pub fn print_parts(parts: &[BenchedPart], options: &BenchOptions) {
+    for part in parts {
+        println!("{}:", part.part);
+        println!("{}", part.report);
+
+        match (&part.comparison, &options.compare) {
+            (Some(comparison), _) => {
+                println!("  Compared to {}:", comparison.previous());
+                print_change("Parse", comparison.parse());
+                print_change("Solve", comparison.solve());
+            }
+            (None, Some(CompareTo::Previous)) => io::print_debug("  No previous run to compare against"),
+            (None, Some(CompareTo::Baseline(name))) => {
+                io::print_debug(format!("  No baseline \"{}\" to compare against", name))
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+fn print_change(phase: &str, change: PhaseChange) {
+    let line = format!("    {}: {}", phase, change);
+    match change.verdict() {
+        ChangeVerdict::Regressed => io::print_error(line),
+        ChangeVerdict::Improved => io::print_success(line),
+        ChangeVerdict::Unchanged => println!("{}", line),
+    }
+}
+
+/// Benchmark the given day, or every solved day of the year, including alternatives.
+pub async fn bench_year(years: &Years, client: &AocClient, options: &BenchOptions, year: u16, day: Option<u8>, format: OutputFormat) -> Result<()> {
+    let solutions = years.get_year(year)
+        .map(|year| all_partial_days(year))
+        .ok_or_else(|| anyhow!("There are no solutions for the year {}", year))?
+        .into_iter()
+        .filter(|solution| day.is_none_or(|day| solution.day() == day))
+        .collect::<Vec<_>>();
+
+    if solutions.is_empty() {
+        match day {
+            Some(day) => bail!("Day {} of {} is not solved yet", day, year),
+            None => bail!("There are no solved days in {} yet", year),
+        }
+    }
+
+    let mut writer = RecordWriter::new(format);
+    let mut failed = 0;
+    for day in &solutions {
+        let res = bench_day(day, client, options).await
+            .with_context(|| format!("Failed to benchmark {}", describe_day(day)));
+
+        match (&mut writer, res) {
+            (None, Ok(parts)) => {
+                println!("{}:", describe_day(day));
+                print_parts(&parts, options);
+            }
+            (None, Err(err)) => {
+                io::print_error(format!("{:?}", err));
+                failed += 1;
+            }
+            (Some(writer), Ok(parts)) => {
+                for part in &parts {
+                    writer.write(&BenchRow::new(day, Some(part), None));
+                }
+            }
+            (Some(writer), Err(err)) => {
+                writer.write(&BenchRow::new(day, None, Some(format!("{:#}", err))));
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        bail!("{} of {} solution(s) failed", failed, solutions.len());
+    }
+
+    Ok(())
+}
+
+/// The structured record of a benchmarked part, durations are in nanoseconds.
+#[derive(Serialize)]
+struct BenchRow {
+    year: u16,
+    day: u8,
+    alt: Option<&'static str>,
+    part: Option<u8>,
+    samples: Option<usize>,
+    shared_parse: Option<bool>,
+    parse_mean_ns: Option<u64>,
+    parse_median_ns: Option<u64>,
+    parse_std_dev_ns: Option<u64>,
+    parse_min_ns: Option<u64>,
+    parse_p95_ns: Option<u64>,
+    parse_max_ns: Option<u64>,
+    parse_mild_outliers: Option<usize>,
+    parse_severe_outliers: Option<usize>,
+    solve_mean_ns: Option<u64>,
+    solve_median_ns: Option<u64>,
+    solve_std_dev_ns: Option<u64>,
+    solve_min_ns: Option<u64>,
+    solve_p95_ns: Option<u64>,
+    solve_max_ns: Option<u64>,
+    solve_mild_outliers: Option<usize>,
+    solve_severe_outliers: Option<usize>,
+    /// The relative change of the mean solve time against the compared run.
+    solve_change: Option<f64>,
+    regressed: Option<bool>,
+    error: Option<String>,
+}
+
+impl BenchRow {
+    fn new(day: &PartialDay, part: Option<&BenchedPart>, error: Option<String>) -> Self {
+        let report = part.map(|part| &part.report);
+        let nanos = |duration: std::time::Duration| duration.as_nanos() as u64;
+
+        Self {
+            year: day.year(),
+            day: day.day(),
+            alt: day.alt(),
+            part: part.map(|part| part.part.level()),
+            samples: report.map(|report| report.solve().samples().len()),
+            shared_parse: report.map(PhaseReport::shared_parse),
+            parse_mean_ns: report.map(|report| nanos(report.parse().mean())),
+            parse_median_ns: report.map(|report| nanos(report.parse().median())),
+            parse_std_dev_ns: report.map(|report| nanos(report.parse().std_dev())),
+            parse_min_ns: report.map(|report| nanos(report.parse().min())),
+            parse_p95_ns: report.map(|report| nanos(report.parse().p95())),
+            parse_max_ns: report.map(|report| nanos(report.parse().max())),
+            parse_mild_outliers: report.map(|report| report.parse().mild_outliers()),
+            parse_severe_outliers: report.map(|report| report.parse().severe_outliers()),
+            solve_mean_ns: report.map(|report| nanos(report.solve().mean())),
+            solve_median_ns: report.map(|report| nanos(report.solve().median())),
+            solve_std_dev_ns: report.map(|report| nanos(report.solve().std_dev())),
+            solve_min_ns: report.map(|report| nanos(report.solve().min())),
+            solve_p95_ns: report.map(|report| nanos(report.solve().p95())),
+            solve_max_ns: report.map(|report| nanos(report.solve().max())),
+            solve_mild_outliers: report.map(|report| report.solve().mild_outliers()),
+            solve_severe_outliers: report.map(|report| report.solve().severe_outliers()),
+            solve_change: part.and_then(|part| part.comparison.as_ref()).map(|comparison| comparison.solve().relative()),
+            regressed: part.and_then(|part| part.comparison.as_ref()).map(BenchComparison::is_regression),
+            error,
+        }
+    }
+}
+
+impl Record for BenchRow {
+    const COLUMNS: &'static [&'static str] = &[
+        "year", "day", "alt", "part", "samples", "shared_parse",
+        "parse_mean_ns", "parse_median_ns", "parse_std_dev_ns",
+        "parse_min_ns", "parse_p95_ns", "parse_max_ns", "parse_mild_outliers", "parse_severe_outliers",
+        "solve_mean_ns", "solve_median_ns", "solve_std_dev_ns",
+        "solve_min_ns", "solve_p95_ns", "solve_max_ns", "solve_mild_outliers", "solve_severe_outliers",
+        "solve_change", "regressed", "error",
+    ];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.year.to_string(),
+            self.day.to_string(),
+            csv_field(&self.alt),
+            csv_field(&self.part),
+            csv_field(&self.samples),
+            csv_field(&self.shared_parse),
+            csv_field(&self.parse_mean_ns),
+            csv_field(&self.parse_median_ns),
+            csv_field(&self.parse_std_dev_ns),
+            csv_field(&self.parse_min_ns),
+            csv_field(&self.parse_p95_ns),
+            csv_field(&self.parse_max_ns),
+            csv_field(&self.parse_mild_outliers),
+            csv_field(&self.parse_severe_outliers),
+            csv_field(&self.solve_mean_ns),
+            csv_field(&self.solve_median_ns),
+            csv_field(&self.solve_std_dev_ns),
+            csv_field(&self.solve_min_ns),
+            csv_field(&self.solve_p95_ns),
+            csv_field(&self.solve_max_ns),
+            csv_field(&self.solve_mild_outliers),
+            csv_field(&self.solve_severe_outliers),
+            csv_field(&self.solve_change),
+            csv_field(&self.regressed),
+            csv_field(&self.error),
+        ]
+    }
+}
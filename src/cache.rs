@@ -0,0 +1,167 @@
+use anyhow::{bail, Result};
+use aoc_lib::cache::{Cache, CacheEntry, CacheProblem};
+use aoc_lib::io;
+use inquire::Confirm;
+use serde::Serialize;
+
+use crate::cli::{CacheCommand, CacheFilter};
+use crate::output::{csv_field, OutputFormat, Record, RecordWriter};
+
+/// Run a cache management command.
+pub fn run(cache: &Cache, command: CacheCommand, format: OutputFormat) -> Result<()> {
+    match command {
+        CacheCommand::List(filter) => list(cache, filter, format),
+        CacheCommand::Verify(filter) => verify(cache, filter, format),
+        CacheCommand::Export { path, filter } => {
+            let exported = cache.export(&path, filter.year, filter.day)?;
+            match RecordWriter::new(format) {
+                Some(mut writer) => {
+                    exported.iter().for_each(|&input| writer.write(&ArchiveRecord::new(input, "exported")));
+                }
+                None => println!("Exported {} input(s) to {}", exported.len(), path.display()),
+            }
+            Ok(())
+        }
+        CacheCommand::Import { path, filter, overwrite } => {
+            let summary = cache.import(&path, filter.year, filter.day, overwrite)?;
+            match RecordWriter::new(format) {
+                Some(mut writer) => {
+                    summary.imported.iter().for_each(|&input| writer.write(&ArchiveRecord::new(input, "imported")));
+                    summary.skipped.iter().for_each(|&input| writer.write(&ArchiveRecord::new(input, "skipped")));
+                }
+                None => println!("Imported {} input(s) into {}", summary.imported.len(), cache.root().display()),
+            }
+            if !summary.skipped.is_empty() {
+                io::print_warning(format!("Skipped {} input(s) which were already cached, use --overwrite to replace them", summary.skipped.len()));
+            }
+            Ok(())
+        }
+        CacheCommand::Purge { filter, yes } => purge(cache, filter, yes),
+    }
+}
+
+fn list(cache: &Cache, filter: CacheFilter, format: OutputFormat) -> Result<()> {
+    let entries = cache.entries(filter.year, filter.day)?;
+
+    if let Some(mut writer) = RecordWriter::new(format) {
+        entries.iter().for_each(|entry| writer.write(&EntryRecord::new(entry, None)));
+        return Ok(());
+    }
+
+    println!("Cache at {}", cache.root().display());
+    for entry in &entries {
+        println!("{} day {:>2}: {} bytes", entry.year, entry.day, entry.size);
+    }
+    println!("{} cached input(s)", entries.len());
+
+    Ok(())
+}
+
+fn verify(cache: &Cache, filter: CacheFilter, format: OutputFormat) -> Result<()> {
+    let checked = cache.entries(filter.year, filter.day)?.len();
+    let problems = cache.verify(filter.year, filter.day)?;
+
+    match RecordWriter::new(format) {
+        Some(mut writer) => {
+            for (entry, problem) in &problems {
+                writer.write(&EntryRecord::new(entry, Some(problem)));
+            }
+        }
+        None => {
+            for (entry, problem) in &problems {
+                io::print_error(format!("{} day {}: {} ({})", entry.year, entry.day, problem, entry.path.display()));
+            }
+        }
+    }
+
+    if !problems.is_empty() {
+        bail!("{} of {} cached input(s) have problems", problems.len(), checked);
+    }
+
+    if format == OutputFormat::Human {
+        io::print_success(format!("All {} cached input(s) look fine", checked));
+    }
+
+    Ok(())
+}
+
+fn purge(cache: &Cache, filter: CacheFilter, yes: bool) -> Result<()> {
+    let count = cache.entries(filter.year, filter.day)?.len();
+    if count == 0 {
+        println!("There are no cached inputs to remove");
+        return Ok(());
+    }
+
+    if !yes {
+        let confirm = Confirm::new(&format!("Remove {} cached input(s) from {}?", count, cache.root().display()))
+            .with_default(false)
+            .prompt()?;
+        if !confirm {
+            return Ok(());
+        }
+    }
+
+    let removed = cache.purge(filter.year, filter.day)?;
+    println!("Removed {} cached input(s)", removed.len());
+
+    Ok(())
+}
+
+/// The structured record of an input which was exported or imported.
+#[derive(Serialize)]
+struct ArchiveRecord {
+    year: u16,
+    day: u8,
+    /// `exported`, `imported` or `skipped`.
+    status: &'static str,
+}
+
+impl ArchiveRecord {
+    fn new((year, day): (u16, u8), status: &'static str) -> Self {
+        Self { year, day, status }
+    }
+}
+
+impl Record for ArchiveRecord {
+    const COLUMNS: &'static [&'static str] = &["year", "day", "status"];
+
+    fn fields(&self) -> Vec<String> {
+        vec![self.year.to_string(), self.day.to_string(), self.status.to_string()]
+    }
+}
+
+/// The structured record of a cached input.
+#[derive(Serialize)]
+struct EntryRecord {
+    year: u16,
+    day: u8,
+    path: String,
+    size: u64,
+    problem: Option<String>,
+}
+
+impl EntryRecord {
+    fn new(entry: &CacheEntry, problem: Option<&CacheProblem>) -> Self {
+        Self {
+            year: entry.year,
+            day: entry.day,
+            path: entry.path.display().to_string(),
+            size: entry.size,
+            problem: problem.map(ToString::to_string),
+        }
+    }
+}
+
+impl Record for EntryRecord {
+    const COLUMNS: &'static [&'static str] = &["year", "day", "path", "size", "problem"];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.year.to_string(),
+            self.day.to_string(),
+            self.path.clone(),
+            self.size.to_string(),
+            csv_field(&self.problem),
+        ]
+    }
+}
@@ -1,4 +1,12 @@
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+use std::time::Duration;
+use aoc_lib::bench::BenchConfig;
+use aoc_lib::input_source::{FileInput, InputSource, StdinInput};
+use aoc_lib::schedule::MAX_DAY;
+use clap::{Args, Parser, Subcommand};
+
+use crate::bench::{BenchOptions, CompareTo};
+use crate::output::OutputFormat;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
@@ -6,16 +14,233 @@ use clap::{Parser, Subcommand};
 pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Commands>,
+    /// How results are written, the interactive prompts always use the human-readable format
+    #[arg(long, global = true, value_enum, default_value_t)]
+    pub format: OutputFormat,
+    /// Only use cached inputs and never contact the aoc server, no session cookie is needed
+    #[arg(long, global = true, env = "AOC_OFFLINE", value_parser = clap::builder::BoolishValueParser::new())]
+    pub offline: bool,
+    /// The account of the config file whose session and cache are used
+    #[arg(long, global = true, env = "AOC_ACCOUNT")]
+    pub account: Option<String>,
+    /// The server to talk to instead of adventofcode.com, e.g. a local server in tests
+    #[arg(long, global = true, env = "AOC_BASE_URL", hide = true)]
+    pub base_url: Option<String>,
 }
 
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// Benchmark the performance of the day's solutions
-    Benchmark
+    Benchmark(BenchArgs),
+    /// Run the solution of a specific day without any prompts
+    Run(RunArgs),
+    /// Run every solved day of a year and print a summary
+    RunAll {
+        /// The year to run, defaults to the current season
+        year: Option<u16>,
+    },
+    /// Count down to the next puzzle unlock, then fetch its input and description right away
+    Wait {
+        /// The year of the puzzle to wait for, defaults to the next puzzle to unlock
+        year: Option<u16>,
+        /// The day of the puzzle to wait for, defaults to the next day of the year to unlock
+        #[arg(requires = "year", value_parser = clap::value_parser!(u8).range(1..=i64::from(MAX_DAY)))]
+        day: Option<u8>,
+    },
+    /// Show the rankings and star times of a private leaderboard
+    Leaderboard(LeaderboardArgs),
+    /// Run a day on the cached input of every account and compare with their known answers
+    Crosscheck(CrosscheckArgs),
+    /// Generate the skeleton of a new day or year and register it in the workspace
+    New {
+        /// The cargo workspace to generate in, defaults to the one around the current directory
+        #[arg(long, global = true)]
+        root: Option<PathBuf>,
+        #[command(subcommand)]
+        command: NewCommand,
+    },
+    /// Manage the cached puzzle inputs
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum NewCommand {
+    /// Create the solution file of a day in the crate of its year
+    Day {
+        /// The year of the puzzle
+        year: u16,
+        /// The day of the puzzle
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=i64::from(MAX_DAY)))]
+        day: u8,
+    },
+    /// Create the crate of a year with the skeleton of its first day
+    Year {
+        /// The year to create
+        year: u16,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CacheCommand {
+    /// List the cached inputs
+    List(CacheFilter),
+    /// Check the cached inputs for empty, truncated or otherwise invalid files
+    Verify(CacheFilter),
+    /// Write the cached inputs into a gzipped tarball
+    Export {
+        /// The tarball to create
+        path: PathBuf,
+        #[command(flatten)]
+        filter: CacheFilter,
+    },
+    /// Add the inputs of a tarball created by export to the cache
+    Import {
+        /// The tarball to read
+        path: PathBuf,
+        #[command(flatten)]
+        filter: CacheFilter,
+        /// Replace inputs which are already cached
+        #[arg(long)]
+        overwrite: bool,
+    },
+    /// Remove cached inputs, answer ledgers and benchmark histories are kept
+    Purge {
+        #[command(flatten)]
+        filter: CacheFilter,
+        /// Don't ask for confirmation
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(Args, Debug)]
+pub struct CacheFilter {
+    /// Only the inputs of this year
+    pub year: Option<u16>,
+    /// Only the input of this day
+    #[arg(requires = "year", value_parser = clap::value_parser!(u8).range(1..=i64::from(MAX_DAY)))]
+    pub day: Option<u8>,
+}
+
+#[derive(Args, Debug)]
+pub struct LeaderboardArgs {
+    /// The year of the leaderboard, defaults to the current season
+    pub year: Option<u16>,
+    /// The id of the leaderboard, defaults to the one in the config file
+    #[arg(long, env = "AOC_LEADERBOARD")]
+    pub id: Option<u64>,
+    /// Only show the star times of this day
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=i64::from(MAX_DAY)))]
+    pub day: Option<u8>,
+}
+
+#[derive(Args, Debug)]
+pub struct CrosscheckArgs {
+    /// The year of the puzzle
+    pub year: u16,
+    /// The day of the puzzle
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=i64::from(MAX_DAY)))]
+    pub day: u8,
+    /// The name of the alternative solution to run
+    #[arg(short, long)]
+    pub alt: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct RunArgs {
+    /// The year of the puzzle
+    pub year: u16,
+    /// The day of the puzzle
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=i64::from(MAX_DAY)))]
+    pub day: u8,
+    /// The part to run, all solved parts are run if omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+    /// The name of the alternative solution to run
+    #[arg(short, long)]
+    pub alt: Option<String>,
+    /// Read the puzzle input from a file instead of the aoc server
+    #[arg(long, value_name = "PATH", conflicts_with = "stdin")]
+    pub input: Option<PathBuf>,
+    /// Read the puzzle input from stdin instead of the aoc server
+    #[arg(long)]
+    pub stdin: bool,
+    /// Run on the example with this number from the puzzle description, and check the answers
+    /// the description gives for it
+    #[arg(long, value_name = "N", conflicts_with_all = ["input", "stdin"], value_parser = clap::value_parser!(u8).range(1..))]
+    pub example: Option<u8>,
+}
+
+impl RunArgs {
+    /// The custom input source, `None` if the input of the puzzle should be used.
+    pub fn custom_input(&self) -> Option<Box<dyn InputSource>> {
+        match (&self.input, self.stdin) {
+            (Some(path), _) => Some(Box::new(FileInput::new(path))),
+            (None, true) => Some(Box::new(StdinInput::new())),
+            (None, false) => None,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct BenchArgs {
+    /// The year to benchmark without any prompts, the interactive prompts are used if omitted
+    pub year: Option<u16>,
+    /// The day to benchmark, including its alternatives, all solved days are benchmarked if omitted
+    #[arg(requires = "year", value_parser = clap::value_parser!(u8).range(1..=i64::from(MAX_DAY)))]
+    pub day: Option<u8>,
+    /// Milliseconds to run the solution before collecting samples
+    #[arg(long, default_value_t = 500)]
+    pub warmup_ms: u64,
+    /// Milliseconds to spend collecting samples
+    #[arg(long, default_value_t = 3000)]
+    pub budget_ms: u64,
+    /// Minimum number of samples to collect, even if the budget is exceeded
+    #[arg(long, default_value_t = 10)]
+    pub min_samples: u32,
+    /// Maximum number of samples to collect, even if the budget is not used up
+    #[arg(long, default_value_t = 100_000)]
+    pub max_samples: u32,
+    /// Compare the results against the previous run
+    #[arg(long, conflicts_with = "baseline")]
+    pub compare: bool,
+    /// Compare the results against the run saved as the named baseline
+    #[arg(long, value_name = "NAME")]
+    pub baseline: Option<String>,
+    /// Save the results as the named baseline
+    #[arg(long, value_name = "NAME")]
+    pub save_baseline: Option<String>,
+}
+
+impl BenchArgs {
+    pub fn config(&self) -> BenchConfig {
+        BenchConfig::default()
+            .with_warmup(Duration::from_millis(self.warmup_ms))
+            .with_budget(Duration::from_millis(self.budget_ms))
+            .with_max_samples(self.max_samples)
+            .with_min_samples(self.min_samples)
+    }
+
+    pub fn options(&self) -> BenchOptions {
+        let mut options = BenchOptions::new(self.config());
+        if let Some(baseline) = &self.baseline {
+            options = options.with_compare(CompareTo::Baseline(baseline.clone()));
+        } else if self.compare {
+            options = options.with_compare(CompareTo::Previous);
+        }
+        if let Some(name) = &self.save_baseline {
+            options = options.with_save_baseline(name.clone());
+        }
+
+        options
+    }
 }
 
 impl Cli {
     pub fn parse() -> Self {
         Parser::parse()
     }
-}
\ No newline at end of file
+}
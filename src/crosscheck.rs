@@ -0,0 +1,175 @@
+use anyhow::{bail, Result};
+use aoc_lib::cache::Cache;
+use aoc_lib::day::PartialDay;
+use aoc_lib::ledger::LedgerCheck;
+use aoc_lib::year::Years;
+use aoc_lib::{io, AocClient, Part};
+use serde::Serialize;
+
+use crate::cli::CrosscheckArgs;
+use crate::output::{csv_field, OutputFormat, Record, RecordWriter};
+use crate::run::{describe_day, resolve_day};
+
+/// The name the shared cache is reported under.
+const SHARED: &str = "shared";
+
+/// Run the day on the cached input of the shared cache and of every account, and compare the
+/// answers with the answer ledger of the respective account.
+///
+/// Only cached inputs are used, so no session is needed.
+pub async fn crosscheck(years: &Years, cache: &Cache, args: CrosscheckArgs, format: OutputFormat) -> Result<()> {
+    let day = resolve_day(years, args.year, args.day, args.alt.as_deref())?;
+
+    let mut caches = vec![cache.clone()];
+    for name in cache.accounts()? {
+        caches.push(cache.for_account(&name)?);
+    }
+
+    let mut writer = RecordWriter::new(format);
+    let mut checked = 0;
+    let mut failed = 0;
+    for cache in caches {
+        let account = cache.account().unwrap_or(SHARED).to_string();
+        let client = AocClient::offline().with_cache(cache);
+        if client.cache().read_input(day.year(), day.day())?.is_none() {
+            continue;
+        }
+        checked += 1;
+
+        let results = check_account(&client, &day).await;
+        failed += results.iter().filter(|result| !result.status.is_ok()).count();
+
+        match &mut writer {
+            Some(writer) => results.iter().for_each(|result| writer.write(&result.record(&account))),
+            None => print_account(&account, &results),
+        }
+    }
+
+    if checked == 0 {
+        bail!("No account has a cached input for day {} of {}", args.day, args.year);
+    }
+    if failed > 0 {
+        bail!("{} part(s) of {} failed or disagreed with a known answer, on {} input(s)", failed, describe_day(&day), checked);
+    }
+
+    Ok(())
+}
+
+enum Status {
+    /// The answer matches the accepted answer of the account.
+    Pass,
+    /// The account has no accepted answer to compare with.
+    Unknown,
+    /// The answer contradicts what is known about the answer of the account.
+    Disagree(LedgerCheck),
+    Error(anyhow::Error),
+}
+
+impl Status {
+    fn is_ok(&self) -> bool {
+        matches!(self, Self::Pass | Self::Unknown)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Unknown => "unknown",
+            Self::Disagree(_) => "disagree",
+            Self::Error(_) => "error",
+        }
+    }
+}
+
+struct PartResult {
+    part: Part,
+    answer: Option<String>,
+    status: Status,
+}
+
+impl PartResult {
+    fn record<'a>(&'a self, account: &'a str) -> CrosscheckRecord<'a> {
+        let detail = match &self.status {
+            Status::Disagree(check) => Some(check.to_string()),
+            Status::Error(err) => Some(format!("{:#}", err)),
+            Status::Pass | Status::Unknown => None,
+        };
+
+        CrosscheckRecord {
+            account,
+            part: self.part.level(),
+            answer: self.answer.as_deref(),
+            status: self.status.name(),
+            detail,
+        }
+    }
+}
+
+async fn check_account(client: &AocClient, day: &PartialDay) -> Vec<PartResult> {
+    let failed = |err: anyhow::Error| {
+        vec![PartResult { part: day.parts()[0], answer: None, status: Status::Error(err) }]
+    };
+
+    let ledger = match client.get_answer_ledger(day.year(), day.day()) {
+        Ok(ledger) => ledger,
+        Err(err) => return failed(err.context("Failed to load the answer ledger")),
+    };
+    let outputs = match day.run_parts(client).await {
+        Ok(outputs) => outputs,
+        Err(err) => return failed(err),
+    };
+
+    outputs.into_iter()
+        .map(|(part, res)| {
+            let output = match res {
+                Ok(output) => output,
+                Err(err) => return PartResult { part, answer: None, status: Status::Error(err) },
+            };
+            let answer = output.answer().to_string();
+
+            let status = match ledger.check(part, &answer) {
+                LedgerCheck::Correct => Status::Pass,
+                LedgerCheck::Unknown => Status::Unknown,
+                check => Status::Disagree(check),
+            };
+
+            PartResult { part, answer: Some(answer), status }
+        })
+        .collect()
+}
+
+fn print_account(account: &str, results: &[PartResult]) {
+    println!("{}:", account);
+    for result in results {
+        let answer = result.answer.as_deref().unwrap_or("-");
+        match &result.status {
+            Status::Pass => io::print_success(format!("  {}: {} matches the accepted answer", result.part, answer)),
+            Status::Unknown => println!("  {}: {} (no accepted answer to compare with)", result.part, answer),
+            Status::Disagree(check) => io::print_error(format!("  {}: {}, {}", result.part, answer, check)),
+            Status::Error(err) => io::print_error(format!("  {}: {:?}", result.part, err)),
+        }
+    }
+}
+
+/// The structured record of a part which was checked on the input of an account.
+#[derive(Serialize)]
+struct CrosscheckRecord<'a> {
+    account: &'a str,
+    part: u8,
+    answer: Option<&'a str>,
+    status: &'static str,
+    detail: Option<String>,
+}
+
+impl Record for CrosscheckRecord<'_> {
+    const COLUMNS: &'static [&'static str] = &["account", "part", "answer", "status", "detail"];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.account.to_string(),
+            self.part.to_string(),
+            csv_field(&self.answer),
+            self.status.to_string(),
+            csv_field(&self.detail),
+        ]
+    }
+}
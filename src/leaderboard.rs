@@ -0,0 +1,150 @@
+use anyhow::{anyhow, Result};
+use aoc_lib::leaderboard::{Leaderboard, Member};
+use aoc_lib::schedule;
+use aoc_lib::util::duration::DisplayCountdown;
+use aoc_lib::year::current_aoc_season;
+use aoc_lib::{io, AocClient, Part};
+use chrono::{DateTime, Duration, Local, Utc};
+use serde::Serialize;
+
+use crate::cli::LeaderboardArgs;
+use crate::output::{csv_field, print_table, OutputFormat, Record, RecordWriter};
+
+/// Show the rankings of the private leaderboard, and the star times of its members.
+pub async fn show(client: &AocClient, args: LeaderboardArgs, default_id: Option<u64>, format: OutputFormat) -> Result<()> {
+    let id = args.id.or(default_id)
+        .ok_or_else(|| anyhow!("No leaderboard given, use --id or set leaderboard in the config file"))?;
+    let year = args.year.unwrap_or_else(current_aoc_season);
+
+    let leaderboard = client.get_private_leaderboard(year, id).await?;
+    let ranking = leaderboard.ranking();
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => leaderboard.days(),
+    };
+
+    if let Some(mut writer) = RecordWriter::new(format) {
+        for (rank, member) in ranking.iter().enumerate() {
+            for &day in &days {
+                writer.write(&LeaderboardRecord::new(year, rank + 1, member, day));
+            }
+        }
+        return Ok(());
+    }
+
+    print_header(&leaderboard, id);
+    let rows = ranking.iter()
+        .enumerate()
+        .map(|(rank, member)| vec![
+            (rank + 1).to_string(),
+            member.name(),
+            member.local_score().to_string(),
+            member.stars().to_string(),
+        ])
+        .collect::<Vec<_>>();
+    print_table(&["Rank", "Name", "Score", "Stars"], &rows);
+
+    for day in days {
+        let rows = ranking.iter()
+            .filter(|member| member.star_time(day, Part::Part1).is_some())
+            .map(|member| vec![
+                member.name(),
+                star_time(year, day, member.star_time(day, Part::Part1)),
+                star_time(year, day, member.star_time(day, Part::Part2)),
+                member.part2_delta(day).map(display_duration).unwrap_or_default(),
+            ])
+            .collect::<Vec<_>>();
+
+        println!();
+        println!("Day {}:", day);
+        if rows.is_empty() {
+            println!("Nobody has a star yet");
+            continue;
+        }
+        print_table(&["Name", "Part 1", "Part 2", "Delta"], &rows);
+    }
+
+    Ok(())
+}
+
+fn print_header(leaderboard: &Leaderboard, id: u64) {
+    println!("Private leaderboard {} of {}", id, leaderboard.event());
+    if let Some(fetched_at) = leaderboard.fetched_at() {
+        io::print_debug(format!(
+            "Fetched at {}, it is fetched at most every 15 minutes",
+            fetched_at.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S")
+        ));
+    }
+    println!();
+}
+
+/// The time the star took since the puzzle unlocked.
+fn star_time(year: u16, day: u8, time: Option<DateTime<Utc>>) -> String {
+    let Some(time) = time else {
+        return String::new();
+    };
+
+    match schedule::unlock_time(year, day) {
+        Some(unlock) => display_duration(time - unlock),
+        None => time.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S").to_string(),
+    }
+}
+
+fn display_duration(duration: Duration) -> String {
+    DisplayCountdown(duration.to_std().unwrap_or_default()).to_string()
+}
+
+/// The structured record of a member's stars on a day, times are seconds since the unlock.
+#[derive(Serialize)]
+struct LeaderboardRecord {
+    rank: usize,
+    id: u64,
+    name: String,
+    score: u64,
+    stars: u32,
+    day: u8,
+    part1_secs: Option<i64>,
+    part2_secs: Option<i64>,
+    delta_secs: Option<i64>,
+}
+
+impl LeaderboardRecord {
+    fn new(year: u16, rank: usize, member: &Member, day: u8) -> Self {
+        let since_unlock = |part| {
+            let unlock = schedule::unlock_time(year, day)?;
+            Some((member.star_time(day, part)? - unlock).num_seconds())
+        };
+
+        Self {
+            rank,
+            id: member.id(),
+            name: member.name(),
+            score: member.local_score(),
+            stars: member.stars(),
+            day,
+            part1_secs: since_unlock(Part::Part1),
+            part2_secs: since_unlock(Part::Part2),
+            delta_secs: member.part2_delta(day).map(|delta| delta.num_seconds()),
+        }
+    }
+}
+
+impl Record for LeaderboardRecord {
+    const COLUMNS: &'static [&'static str] = &[
+        "rank", "id", "name", "score", "stars", "day", "part1_secs", "part2_secs", "delta_secs",
+    ];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.rank.to_string(),
+            self.id.to_string(),
+            self.name.clone(),
+            self.score.to_string(),
+            self.stars.to_string(),
+            self.day.to_string(),
+            csv_field(&self.part1_secs),
+            csv_field(&self.part2_secs),
+            csv_field(&self.delta_secs),
+        ]
+    }
+}
@@ -1,24 +1,121 @@
-use std::env;
+use std::process::ExitCode;
+use aoc_lib::config::Config;
 use aoc_lib::{io, AocClient};
 
-use crate::cli::{Cli, Commands};
+use crate::cli::{Cli, Commands, NewCommand};
+use crate::output::OutputFormat;
 
+mod bench;
+mod cache;
 mod cli;
+mod crosscheck;
+mod leaderboard;
+mod output;
+mod scaffold;
 mod years;
 mod tui;
+mod run;
+mod wait;
 
 #[tokio::main]
-async fn main() {
+async fn main() -> ExitCode {
     let cli = Cli::parse();
+    let format = cli.format;
 
-    let benchmark = matches!(cli.command, Some(Commands::Benchmark));
-
-    let Ok(session_cookie) = env::var("AOC_SESSION_COOKIE") else {
-        io::print_error("AOC_SESSION_COOKIE environment variable must be set");
-        return;
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(err) => return exit_code(Err(err), format),
+    };
+    let shared_cache = match config.cache() {
+        Ok(cache) => cache,
+        Err(err) => return exit_code(Err(err), format),
+    };
+    let cache = match config.credentials().active_account(cli.account.as_deref()) {
+        Some(account) => match shared_cache.for_account(account) {
+            Ok(cache) => cache,
+            Err(err) => return exit_code(Err(err.into()), format),
+        },
+        None => shared_cache.clone(),
     };
-    let client = AocClient::new(session_cookie);
     let years = years::years();
 
-    tui::run_tui(&years, &client, benchmark).await;
+    // Managing the cache, cross-checking cached inputs and scaffolding don't need a session
+    let command = match cli.command {
+        Some(Commands::Cache { command }) => return exit_code(cache::run(&cache, command, format), format),
+        Some(Commands::New { root, command }) => {
+            let res = match command {
+                NewCommand::Day { year, day } => scaffold::new_day(&cache, root, year, day, format).await,
+                NewCommand::Year { year } => scaffold::new_year(&cache, root, year, format).await,
+            };
+            return exit_code(res, format);
+        }
+        Some(Commands::Crosscheck(args)) => {
+            return exit_code(crosscheck::crosscheck(&years, &shared_cache, args, format).await, format);
+        }
+        command => command,
+    };
+
+    let client = if cli.offline {
+        AocClient::offline()
+    } else {
+        match config.session(cli.account.as_deref()) {
+            Ok(session) => AocClient::new(&session),
+            Err(err) => return exit_code(Err(err), format),
+        }
+    };
+    let client = match cli.base_url {
+        Some(base_url) => client.with_base_url(base_url),
+        None => client,
+    };
+    let client = client.with_cache(cache);
+
+    match command {
+        None => tui::run_tui(&years, &client, None).await,
+        Some(Commands::Benchmark(args)) => {
+            let options = args.options();
+            let Some(year) = args.year else {
+                if format != OutputFormat::Human {
+                    eprintln!("Structured output needs the year to benchmark");
+                    return ExitCode::FAILURE;
+                }
+                tui::run_tui(&years, &client, Some(&options)).await;
+                return ExitCode::SUCCESS;
+            };
+
+            return exit_code(bench::bench_year(&years, &client, &options, year, args.day, format).await, format);
+        }
+        Some(Commands::Run(args)) => {
+            return exit_code(run::run(&years, &client, args, format).await, format);
+        }
+        Some(Commands::RunAll { year }) => {
+            return exit_code(run::run_all(&years, &client, year, format).await, format);
+        }
+        Some(Commands::Leaderboard(args)) => {
+            let res = leaderboard::show(&client, args, config.leaderboard(), format).await;
+            return exit_code(res, format);
+        }
+        Some(Commands::Wait { year, day }) => {
+            return exit_code(wait::wait(&client, year, day, format).await, format);
+        }
+        Some(Commands::Cache { .. } | Commands::Crosscheck(_) | Commands::New { .. }) => {
+            unreachable!("Commands without a session are handled before the client is created")
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Report the error if there is one, structured output keeps errors out of stdout.
+fn exit_code(res: anyhow::Result<()>, format: OutputFormat) -> ExitCode {
+    match res {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) if format == OutputFormat::Human => {
+            io::print_error(format!("{:?}", err));
+            ExitCode::FAILURE
+        }
+        Err(err) => {
+            eprintln!("{:?}", err);
+            ExitCode::FAILURE
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,115 @@
+use std::fmt::Display;
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// How results are written to stdout.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text and tables
+    #[default]
+    Human,
+    /// One JSON object per line
+    Json,
+    /// Comma separated values with a header row
+    Csv,
+}
+
+/// A structured result which can be written as JSON or CSV.
+pub trait Record: Serialize {
+    /// The CSV column names, in the same order as the fields.
+    const COLUMNS: &'static [&'static str];
+
+    /// The CSV fields of the record.
+    fn fields(&self) -> Vec<String>;
+}
+
+/// Writes records to stdout in a machine-readable format.
+pub struct RecordWriter {
+    format: OutputFormat,
+    header_written: bool,
+}
+
+impl RecordWriter {
+    /// Create a writer for the format, `None` if the format is meant for humans.
+    pub fn new(format: OutputFormat) -> Option<Self> {
+        match format {
+            OutputFormat::Human => None,
+            OutputFormat::Json | OutputFormat::Csv => Some(Self {
+                format,
+                header_written: false,
+            }),
+        }
+    }
+
+    pub fn write<R: Record>(&mut self, record: &R) {
+        match self.format {
+            OutputFormat::Human => unreachable!("There is no writer for human-readable output"),
+            OutputFormat::Json => {
+                let json = serde_json::to_string(record).expect("Records should always serialize");
+                println!("{}", json);
+            }
+            OutputFormat::Csv => {
+                if !self.header_written {
+                    println!("{}", R::COLUMNS.join(","));
+                    self.header_written = true;
+                }
+                println!("{}", record.fields().iter().map(|field| csv_escape(field)).collect::<Vec<_>>().join(","));
+            }
+        }
+    }
+}
+
+/// Print rows as a table with aligned columns, for human-readable output.
+pub fn print_table<S: AsRef<str>>(header: &[&str], rows: &[Vec<S>]) {
+    let widths = (0..header.len())
+        .map(|i| {
+            rows.iter()
+                .filter_map(|row| row.get(i).map(|cell| cell.as_ref().chars().count()))
+                .chain([header[i].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+
+    let print_row = |row: &mut dyn Iterator<Item = &str>| {
+        let line = row
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        println!("{}", line.trim_end());
+    };
+
+    print_row(&mut header.iter().copied());
+    println!("{}", widths.iter().map(|&width| "-".repeat(width)).collect::<Vec<_>>().join("-+-"));
+    for row in rows {
+        print_row(&mut row.iter().map(AsRef::as_ref));
+    }
+}
+
+/// Format an optional value as a CSV field, `None` is an empty field.
+pub fn csv_field<T: Display>(value: &Option<T>) -> String {
+    value.as_ref().map(ToString::to_string).unwrap_or_default()
+}
+
+/// Quote the field if it contains a separator, a quote or a line break.
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_escape() {
+        assert_eq!(csv_escape("123"), "123");
+        assert_eq!(csv_escape("a,b"), "\"a,b\"");
+        assert_eq!(csv_escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_escape("line\nbreak"), "\"line\nbreak\"");
+    }
+}
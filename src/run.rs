@@ -0,0 +1,411 @@
+use std::time::Duration;
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_lib::aoc_client::{MissingInput, MissingInputs};
+use aoc_lib::day::PartialDay;
+use aoc_lib::description::ExtractedExample;
+use aoc_lib::input_source::{InputSource, TextInput};
+use aoc_lib::ledger::LedgerCheck;
+use aoc_lib::puzzle::SolutionOutput;
+use aoc_lib::util::duration::DisplayDuration;
+use aoc_lib::year::{Year, Years};
+use aoc_lib::{io, AocClient, Part};
+use itertools::Itertools;
+use serde::Serialize;
+
+use crate::cli::RunArgs;
+use crate::output::{self, csv_field, OutputFormat, Record, RecordWriter};
+
+/// Run the requested day without any prompts, returns an error if anything failed.
+pub async fn run(years: &Years, client: &AocClient, args: RunArgs, format: OutputFormat) -> Result<()> {
+    let day = resolve_day(years, args.year, args.day, args.alt.as_deref())?;
+
+    let example = match args.example {
+        Some(number) => Some(load_example(client, &day, number).await?),
+        None => None,
+    };
+    let custom_input = match &example {
+        Some(example) => Some(Box::new(TextInput::new(example.input())) as Box<dyn InputSource>),
+        None => args.custom_input(),
+    };
+    let source = custom_input.as_deref().unwrap_or(client);
+
+    let outputs = match args.part {
+        None => day.run_parts(source).await?,
+        Some(1) => vec![(Part::Part1, day.run(Part::Part1, source).await)],
+        Some(_) => vec![(Part::Part2, day.run(Part::Part2, source).await)],
+    };
+
+    let mut writer = RecordWriter::new(format);
+    let mut failed = false;
+    for (part, res) in outputs {
+        // The answer ledger only knows about the answers for the puzzle input
+        let res = res.and_then(|output| {
+            let check = match (&example, &custom_input) {
+                (Some(example), _) => check_example(example, part, &output)?,
+                (None, Some(_)) => LedgerCheck::Unknown,
+                (None, None) => check_output(client, &day, part, &output)?,
+            };
+            Ok((output, check))
+        });
+
+        let row = Row::new(&day, part, &res);
+        failed |= row.is_error();
+
+        if let Some(writer) = &mut writer {
+            writer.write(&row.record());
+            continue;
+        }
+
+        println!("{}:", part);
+        match res {
+            Ok((output, _)) => println!("{}", output.message()),
+            Err(err) => io::print_error(format!("{:?}", err)),
+        }
+    }
+
+    if failed {
+        bail!("Running day {} of {} failed", args.day, args.year);
+    }
+
+    Ok(())
+}
+
+/// Run every solved day of the year, including alternatives, and print a summary table.
+pub async fn run_all(years: &Years, client: &AocClient, year: Option<u16>, format: OutputFormat) -> Result<()> {
+    let year = match year {
+        Some(year) => years.get_year(year)
+            .ok_or_else(|| anyhow!("There are no solutions for the year {}", year))?,
+        None => years.current(),
+    };
+
+    let mut writer = RecordWriter::new(format);
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for day in all_partial_days(year) {
+        let first_row = rows.len();
+        match day.run_parts(client).await {
+            Ok(outputs) => {
+                for (part, res) in outputs {
+                    let res = res.and_then(|output| {
+                        let check = check_output(client, &day, part, &output)?;
+                        Ok((output, check))
+                    });
+
+                    rows.push(Row::new(&day, part, &res));
+                    if let Err(err) = res {
+                        errors.push((format!("{} {}", describe_day(&day), part), err));
+                    }
+                }
+            }
+            Err(err) => {
+                rows.extend(day.parts().iter().map(|&part| Row::failed(&day, part, &err)));
+                errors.push((describe_day(&day), err));
+            }
+        }
+
+        // Stream the records, so scripts see the results of slow days as soon as possible
+        if let Some(writer) = &mut writer {
+            rows[first_row..].iter().for_each(|row| writer.write(&row.record()));
+        }
+    }
+
+    if writer.is_none() {
+        print_table(year.year(), &rows);
+
+        let complete = year.get_partial().filter(PartialDay::is_complete).count();
+        println!("{} of {} days complete", complete, year.day_count());
+
+        for (what, err) in &errors {
+            io::print_error(format_args!("{}: {:?}", what, err));
+        }
+    }
+
+    let failed = rows.iter().filter(|row| row.is_error()).count();
+    if failed > 0 {
+        let missing = errors.iter()
+            .filter_map(|(_, err)| err.downcast_ref::<MissingInput>())
+            .copied()
+            .collect::<Vec<_>>();
+        let err = if missing.is_empty() {
+            anyhow!("{} of {} part(s) failed", failed, rows.len())
+        } else {
+            anyhow::Error::new(MissingInputs::new(missing))
+                .context(format!("{} of {} part(s) failed", failed, rows.len()))
+        };
+
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+/// Look up the solution of the day, or one of its alternatives.
+pub fn resolve_day(years: &Years, year: u16, day: u8, alt: Option<&str>) -> Result<PartialDay> {
+    let solutions = years.get_year(year)
+        .ok_or_else(|| anyhow!("There are no solutions for the year {}", year))?;
+
+    let (default, alternatives) = solutions.get_day_full(day)?;
+    let solution = match alt {
+        None => default,
+        Some(alt) => alternatives
+            .and_then(|alternatives| alternatives.get(alt))
+            .ok_or_else(|| {
+                let available = alternatives.into_iter()
+                    .flat_map(|alternatives| alternatives.keys())
+                    .sorted()
+                    .map(|alt| format!("\"{}\"", alt))
+                    .join(", ");
+                anyhow!("Day {} has no alternative \"{}\" (available: [{}])", day, alt, available)
+            })?,
+    };
+
+    solution.try_into_partial()
+        .ok_or_else(|| anyhow!("Day {} of {} is not solved yet", day, year))
+}
+
+pub fn describe_day(day: &PartialDay) -> String {
+    match day.alt() {
+        Some(alt) => format!("Day {} ({})", day.day(), alt),
+        None => format!("Day {}", day.day()),
+    }
+}
+
+/// All days of the year which have a solution, each followed by its alternatives.
+pub fn all_partial_days(year: &Year) -> Vec<PartialDay> {
+    year.get_partial()
+        .flat_map(|day| {
+            let alternatives = year.get_alternatives_for(day.day())
+                .ok()
+                .flatten()
+                .into_iter()
+                .flat_map(|alternatives| alternatives.iter())
+                .filter(|(_, alt)| alt.alt() != day.alt())
+                .sorted_by_key(|(&key, _)| key)
+                .filter_map(|(_, alt)| alt.try_into_partial())
+                .collect::<Vec<_>>();
+
+            std::iter::once(day).chain(alternatives)
+        })
+        .collect()
+}
+
+/// Compare the answer against the answer ledger, answers known to be wrong are an error.
+fn check_output(client: &AocClient, day: &PartialDay, part: Part, output: &SolutionOutput) -> Result<LedgerCheck> {
+    let check = client.check_output(day.year(), day.day(), part, output)?;
+    if check.is_known_wrong() {
+        bail!("{}: {}", check, output.answer());
+    }
+
+    Ok(check)
+}
+
+/// Load the example with the number, starting at 1, from the puzzle description.
+pub async fn load_example(client: &AocClient, day: &PartialDay, number: u8) -> Result<ExtractedExample> {
+    let examples = client.get_examples(day.year(), day.day()).await
+        .context("Failed to load the examples of the puzzle description")?;
+
+    let count = examples.len();
+    examples.into_iter()
+        .nth(number as usize - 1)
+        .ok_or_else(|| anyhow!("Day {} has {} example(s), there is no example {}", day.day(), count, number))
+}
+
+/// Compare the answer against the answer the description gives for the example, a different
+/// answer is an error.
+pub fn check_example(example: &ExtractedExample, part: Part, output: &SolutionOutput) -> Result<LedgerCheck> {
+    let Some(expected) = example.expected(part) else {
+        return Ok(LedgerCheck::Unknown);
+    };
+
+    let answer = output.answer().to_string();
+    if answer != expected {
+        bail!("The example expects {}, but the answer is {}", expected, answer);
+    }
+
+    Ok(LedgerCheck::Correct)
+}
+
+enum Status {
+    Correct,
+    Unverified,
+    Error,
+}
+
+impl From<LedgerCheck> for Status {
+    fn from(check: LedgerCheck) -> Self {
+        match check {
+            LedgerCheck::Correct => Self::Correct,
+            _ => Self::Unverified,
+        }
+    }
+}
+
+struct Timings {
+    parse: Duration,
+    solve: Duration,
+    shared_parse: bool,
+}
+
+impl Timings {
+    fn parse(&self) -> String {
+        if self.shared_parse {
+            "shared".to_string()
+        } else {
+            DisplayDuration(self.parse).to_string()
+        }
+    }
+
+    fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+impl From<&SolutionOutput> for Timings {
+    fn from(output: &SolutionOutput) -> Self {
+        Self {
+            parse: output.parse_time(),
+            solve: output.solve_time(),
+            shared_parse: output.shared_parse(),
+        }
+    }
+}
+
+struct Row {
+    year: u16,
+    day: u8,
+    alt: &'static str,
+    part: Part,
+    answer: String,
+    output: Option<Timings>,
+    status: Status,
+    /// The error of a failed part, on a single line.
+    error: Option<String>,
+}
+
+impl Row {
+    fn new(day: &PartialDay, part: Part, res: &Result<(SolutionOutput, LedgerCheck)>) -> Self {
+        let (output, check) = match res {
+            Ok(res) => res,
+            Err(err) => return Self::failed(day, part, err),
+        };
+
+        Self {
+            year: day.year(),
+            day: day.day(),
+            alt: day.alt().unwrap_or(""),
+            part,
+            answer: output.answer().to_string(),
+            output: Some(Timings::from(output)),
+            status: Status::from(check.clone()),
+            error: None,
+        }
+    }
+
+    fn failed(day: &PartialDay, part: Part, err: &anyhow::Error) -> Self {
+        Self {
+            year: day.year(),
+            day: day.day(),
+            alt: day.alt().unwrap_or(""),
+            part,
+            answer: String::new(),
+            output: None,
+            status: Status::Error,
+            error: Some(format!("{:#}", err)),
+        }
+    }
+
+    fn is_error(&self) -> bool {
+        matches!(self.status, Status::Error)
+    }
+
+    fn status(&self) -> &'static str {
+        match self.status {
+            Status::Correct => "correct",
+            Status::Unverified => "ok",
+            Status::Error => "error",
+        }
+    }
+
+    fn cells(&self) -> [String; 9] {
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            self.alt.to_string(),
+            self.part.level().to_string(),
+            self.answer.clone(),
+            self.output.as_ref().map(Timings::parse).unwrap_or_default(),
+            self.output.as_ref().map(|output| DisplayDuration(output.solve).to_string()).unwrap_or_default(),
+            self.output.as_ref().map(|output| DisplayDuration(output.total()).to_string()).unwrap_or_default(),
+            self.status().to_string(),
+        ]
+    }
+
+    fn record(&self) -> RunRecord<'_> {
+        let nanos = |duration: Duration| duration.as_nanos() as u64;
+
+        RunRecord {
+            year: self.year,
+            day: self.day,
+            alt: Some(self.alt).filter(|alt| !alt.is_empty()),
+            part: self.part.level(),
+            answer: self.output.as_ref().map(|_| self.answer.as_str()),
+            parse_ns: self.output.as_ref().map(|output| nanos(output.parse)),
+            solve_ns: self.output.as_ref().map(|output| nanos(output.solve)),
+            total_ns: self.output.as_ref().map(|output| nanos(output.total())),
+            shared_parse: self.output.as_ref().map(|output| output.shared_parse),
+            status: self.status(),
+            error: self.error.as_deref(),
+        }
+    }
+}
+
+/// The structured record of a part which was run, durations are in nanoseconds.
+#[derive(Serialize)]
+struct RunRecord<'a> {
+    year: u16,
+    day: u8,
+    alt: Option<&'a str>,
+    part: u8,
+    answer: Option<&'a str>,
+    parse_ns: Option<u64>,
+    solve_ns: Option<u64>,
+    total_ns: Option<u64>,
+    shared_parse: Option<bool>,
+    status: &'static str,
+    error: Option<&'a str>,
+}
+
+impl Record for RunRecord<'_> {
+    const COLUMNS: &'static [&'static str] = &[
+        "year", "day", "alt", "part", "answer", "parse_ns", "solve_ns", "total_ns", "shared_parse", "status", "error",
+    ];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.year.to_string(),
+            self.day.to_string(),
+            csv_field(&self.alt),
+            self.part.to_string(),
+            csv_field(&self.answer),
+            csv_field(&self.parse_ns),
+            csv_field(&self.solve_ns),
+            csv_field(&self.total_ns),
+            csv_field(&self.shared_parse),
+            self.status.to_string(),
+            csv_field(&self.error),
+        ]
+    }
+}
+
+fn print_table(year: u16, rows: &[Row]) {
+    const HEADER: [&str; 9] = ["Year", "Day", "Alternative", "Part", "Answer", "Parse", "Solve", "Total", "Status"];
+
+    let cells = rows.iter().map(|row| row.cells().to_vec()).collect::<Vec<_>>();
+    output::print_table(&HEADER, &cells);
+
+    let total = rows.iter()
+        .filter_map(|row| row.output.as_ref().map(Timings::total))
+        .sum::<Duration>();
+    println!();
+    println!("Ran {} part(s) of {} in {}", rows.len(), year, DisplayDuration(total));
+}
@@ -0,0 +1,314 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_lib::cache::Cache;
+use aoc_lib::description::ExtractedExample;
+use aoc_lib::{io, schedule, AocClient, Part};
+use serde::Serialize;
+
+use crate::output::{OutputFormat, Record, RecordWriter};
+
+const DAY_TEMPLATE: &str = include_str!("templates/day.rs.tmpl");
+const LIB_TEMPLATE: &str = include_str!("templates/lib.rs.tmpl");
+const PRELUDE_TEMPLATE: &str = include_str!("templates/prelude.rs.tmpl");
+const BUILD_TEMPLATE: &str = include_str!("templates/build.rs.tmpl");
+const CARGO_TEMPLATE: &str = include_str!("templates/Cargo.toml.tmpl");
+
+/// A file written by the scaffolding.
+#[derive(Debug, Serialize)]
+struct ChangedFile {
+    path: String,
+    /// `created` or `updated`.
+    change: &'static str,
+}
+
+impl Record for ChangedFile {
+    const COLUMNS: &'static [&'static str] = &["path", "change"];
+
+    fn fields(&self) -> Vec<String> {
+        vec![self.path.clone(), self.change.to_string()]
+    }
+}
+
+/// Collects the changes to the workspace, so nothing is written if any edit fails.
+struct Changes {
+    root: PathBuf,
+    files: Vec<(PathBuf, String, &'static str)>,
+}
+
+impl Changes {
+    fn new(root: &Path) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            files: Vec::new(),
+        }
+    }
+
+    fn create(&mut self, path: impl AsRef<Path>, content: String) -> Result<()> {
+        let path = self.root.join(path);
+        if path.exists() {
+            bail!("{} exists already", path.display());
+        }
+
+        self.files.push((path, content, "created"));
+        Ok(())
+    }
+
+    /// Edit an existing file of the workspace.
+    fn update(&mut self, path: impl AsRef<Path>, edit: impl FnOnce(&str) -> Result<String>) -> Result<()> {
+        let path = self.root.join(path);
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let content = edit(&content)
+            .with_context(|| format!("Failed to update {}", path.display()))?;
+
+        self.files.push((path, content, "updated"));
+        Ok(())
+    }
+
+    fn write(self, format: OutputFormat) -> Result<()> {
+        let mut writer = RecordWriter::new(format);
+        for (path, content, change) in self.files {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create {}", parent.display()))?;
+            }
+            fs::write(&path, content)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+
+            let path = path.strip_prefix(&self.root).unwrap_or(&path).display().to_string();
+            match &mut writer {
+                Some(writer) => writer.write(&ChangedFile { path, change }),
+                None => io::print_success(format!("{} {}", capitalize(change), path)),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Create the solution file of the day in the crate of its year, where the build script
+/// registers it.
+///
+/// The examples of the puzzle description are filled in if the description is cached.
+pub async fn new_day(cache: &Cache, root: Option<PathBuf>, year: u16, day: u8, format: OutputFormat) -> Result<()> {
+    if day > schedule::last_day(year) {
+        bail!("The season of {} has only {} days", year, schedule::last_day(year));
+    }
+
+    let root = workspace_root(root)?;
+    let crate_dir = year_dir(year);
+    if !root.join(&crate_dir).exists() {
+        bail!("There is no crate for {} yet, create it with `aoc new year {}`", year, year);
+    }
+
+    let examples = cached_examples(cache, year, day).await;
+
+    let mut changes = Changes::new(&root);
+    changes.create(crate_dir.join(format!("src/day{}.rs", day)), day_file(day, &examples))?;
+    changes.write(format)
+}
+
+/// Create the crate of the year with the skeleton of its first day, and register it in the
+/// workspace.
+pub async fn new_year(cache: &Cache, root: Option<PathBuf>, year: u16, format: OutputFormat) -> Result<()> {
+    let root = workspace_root(root)?;
+    let crate_dir = year_dir(year);
+    if root.join(&crate_dir).exists() {
+        bail!("{} exists already", root.join(&crate_dir).display());
+    }
+
+    let examples = cached_examples(cache, year, 1).await;
+    let year_text = year.to_string();
+    let values = [("year", year_text.as_str())];
+
+    let mut changes = Changes::new(&root);
+    changes.create(crate_dir.join("Cargo.toml"), fill(CARGO_TEMPLATE, &values))?;
+    changes.create(crate_dir.join("build.rs"), BUILD_TEMPLATE.to_string())?;
+    changes.create(crate_dir.join("src/lib.rs"), fill(LIB_TEMPLATE, &values))?;
+    changes.create(crate_dir.join("src/prelude.rs"), PRELUDE_TEMPLATE.to_string())?;
+    changes.create(crate_dir.join("src/day1.rs"), day_file(1, &examples))?;
+    changes.update("Cargo.toml", |manifest| register_crate(manifest, year))?;
+    changes.update("src/years.rs", |years| register_year(years, year))?;
+    changes.write(format)
+}
+
+/// The workspace the files are generated in, by default the closest directory with a workspace
+/// manifest around the current directory.
+fn workspace_root(root: Option<PathBuf>) -> Result<PathBuf> {
+    if let Some(root) = root {
+        if !root.join("Cargo.toml").exists() {
+            bail!("{} is not a cargo workspace", root.display());
+        }
+        return Ok(root);
+    }
+
+    let cwd = std::env::current_dir().context("Failed to get the current directory")?;
+    cwd.ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+        })
+        .map(Path::to_path_buf)
+        .ok_or_else(|| anyhow!("No cargo workspace found around {}, pass it with --root", cwd.display()))
+}
+
+fn year_dir(year: u16) -> PathBuf {
+    PathBuf::from(format!("years/year-{}", year))
+}
+
+/// The examples of the cached puzzle description, the server isn't asked for it.
+async fn cached_examples(cache: &Cache, year: u16, day: u8) -> Vec<ExtractedExample> {
+    let client = AocClient::offline().with_cache(cache.clone());
+    client.get_examples(year, day).await.unwrap_or_default()
+}
+
+fn fill(template: &str, values: &[(&str, &str)]) -> String {
+    values.iter().fold(template.to_string(), |text, (key, value)| {
+        text.replace(&format!("{{{{{}}}}}", key), value)
+    })
+}
+
+fn day_file(day: u8, examples: &[ExtractedExample]) -> String {
+    let examples = if examples.is_empty() {
+        // An example without answers is not checked, so the tests pass until it is filled in
+        example_entry(&ExtractedExample::new(""))
+    } else {
+        examples.iter().map(example_entry).collect()
+    };
+
+    fill(DAY_TEMPLATE, &[("day", &day.to_string()), ("examples", &examples)])
+}
+
+/// An entry of the examples of `create_solution!`.
+fn example_entry(example: &ExtractedExample) -> String {
+    // A string continuation would swallow the indentation of the first line
+    let continuation = if example.input().starts_with(char::is_whitespace) { "" } else { "\\\n" };
+
+    let mut entry = format!("    {{\n        input: \"{}{}\",\n", continuation, escape(example.input()));
+    for part in Part::ALL {
+        if let Some(expected) = example.expected(part) {
+            entry += &format!("        part{}: \"{}\",\n", part.level(), escape(expected));
+        }
+    }
+    entry += "    },\n";
+
+    entry
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Add the crate of the year to the workspace members and the dependencies of the binary, both
+/// in order of the years.
+fn register_crate(manifest: &str, year: u16) -> Result<String> {
+    let member = format!("\"years/year-{}\"", year);
+    let mut lines = manifest.lines().map(str::to_string).collect::<Vec<_>>();
+
+    let start = lines.iter().position(|line| line.trim() == "members = [")
+        .ok_or_else(|| anyhow!("Found no workspace members"))?;
+    let end = lines[start..].iter().position(|line| line.trim() == "]")
+        .ok_or_else(|| anyhow!("Found no end of the workspace members"))? + start;
+    if lines[start..end].iter().any(|line| line.trim().trim_end_matches(',') == member) {
+        bail!("{} is a workspace member already", member);
+    }
+    let later = (start + 1..end).find(|&i| line_year(&lines[i], "\"years/year-").is_some_and(|other| other > year));
+    match later {
+        Some(i) => lines.insert(i, format!("    {},", member)),
+        None => {
+            if end - 1 > start && !lines[end - 1].trim_end().ends_with(',') {
+                lines[end - 1].push(',');
+            }
+            lines.insert(end, format!("    {}", member));
+        }
+    }
+
+    let dependency = format!("year-{} = {{ path = \"years/year-{}\" }}", year, year);
+    let index = insert_index(&lines, year, "year-")
+        .or_else(|| lines.iter().position(|line| line.trim() == "[dependencies]").map(|i| i + 1))
+        .ok_or_else(|| anyhow!("Found no [dependencies]"))?;
+    lines.insert(index, dependency);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add the year to `years()` of the binary, in order of the years.
+fn register_year(years: &str, year: u16) -> Result<String> {
+    let call = format!("    years.add_year(year_{}::year());", year);
+    let mut lines = years.lines().map(str::to_string).collect::<Vec<_>>();
+    if lines.contains(&call) {
+        bail!("{} is registered already", year);
+    }
+
+    let index = insert_index(&lines, year, "years.add_year(year_")
+        .or_else(|| lines.iter().position(|line| line.trim() == "years").map(|i| i - 1))
+        .ok_or_else(|| anyhow!("Found nowhere to add the year"))?;
+    lines.insert(index, call);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Where a line for the year goes among the lines of other years starting with the prefix,
+/// `None` if there are no such lines.
+fn insert_index(lines: &[String], year: u16, prefix: &str) -> Option<usize> {
+    let years = lines.iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, line_year(line, prefix)?)))
+        .collect::<Vec<_>>();
+
+    years.iter()
+        .find(|&&(_, other)| other > year)
+        .map(|&(i, _)| i)
+        .or_else(|| years.last().map(|&(i, _)| i + 1))
+}
+
+/// The year right after the prefix of the trimmed line.
+fn line_year(line: &str, prefix: &str) -> Option<u16> {
+    let rest = line.trim_start().strip_prefix(prefix)?;
+    let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    rest[..end].parse().ok()
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_crate() {
+        let manifest = "\
+[workspace]
+members = [
+    \"aoc_lib\",
+    \"years/year-2024\"
+]
+
+[dependencies]
+anyhow.workspace = true
+
+year-2024 = { path = \"years/year-2024\" }
+";
+        let manifest = register_crate(manifest, 2025).unwrap();
+        assert!(manifest.contains("    \"years/year-2024\",\n    \"years/year-2025\"\n]"));
+        assert!(manifest.ends_with("year-2024 = { path = \"years/year-2024\" }\nyear-2025 = { path = \"years/year-2025\" }\n"));
+        assert!(register_crate(&manifest, 2025).is_err());
+
+        let manifest = register_crate(&manifest, 2023).unwrap();
+        assert!(manifest.contains("    \"aoc_lib\",\n    \"years/year-2023\",\n    \"years/year-2024\","));
+        assert!(manifest.contains("true\n\nyear-2023 = { path = \"years/year-2023\" }\nyear-2024"));
+    }
+
+    #[test]
+    fn test_example_entry() {
+        let entry = example_entry(&ExtractedExample::new("  a \"b\"\n"));
+        assert_eq!(entry, "    {\n        input: \"  a \\\"b\\\"\n\",\n    },\n");
+    }
+}
@@ -1,20 +1,27 @@
-use aoc_lib::day::PartialDay;
+use aoc_lib::calendar::Calendar;
+use aoc_lib::day::{Day, PartialDay};
+use aoc_lib::input_source::TextInput;
+use aoc_lib::ledger::LedgerCheck;
 use aoc_lib::year::{Year, Years};
-use aoc_lib::AocClient;
+use aoc_lib::{AocClient, Part};
 use inquire::{Confirm, InquireError, Select};
 use std::sync::Arc;
 use itertools::Itertools;
 
 mod util;
 mod select;
+mod submit;
 
 use util::handle_inquire_res;
-use select::{DisplayPartialDay, DisplayYear, Part};
+use select::{DayAction, DisplayExample, DisplayPartialDay, DisplayYear};
+use crate::bench::{self, BenchOptions};
+use crate::run;
 use crate::tui::select::DisplayAlternatives;
 
-pub async fn run_tui(years: &Years, client: &AocClient, benchmark: bool) {
+/// Run the interactive prompts, solutions are benchmarked instead of run if options are given.
+pub async fn run_tui(years: &Years, client: &AocClient, benchmark: Option<&BenchOptions>) {
     loop {
-        let year = prompt_year(years, benchmark);
+        let year = prompt_year(years, benchmark.is_some());
         let Ok(year) = handle_inquire_res(year) else {
             return;
         };
@@ -56,16 +63,24 @@ fn prompt_year(years: &Years, benchmark: bool) -> Result<Arc<Year>, InquireError
         .map(|year| year.year().clone())
 }
 
-async fn year_loop(year: Arc<Year>, client: &AocClient, benchmark: bool) {
-    let message = if benchmark {
+async fn year_loop(year: Arc<Year>, client: &AocClient, benchmark: Option<&BenchOptions>) {
+    let message = if benchmark.is_some() {
         "Which day do you want to benchmark?"
     } else {
         "Which day do you want to run?"
     };
 
+    let calendar = match benchmark {
+        None => load_calendar(&year, client).await,
+        Some(_) => None,
+    };
+
     loop {
         let days = year.get_partial()
-            .map(|day| DisplayPartialDay::new(day))
+            .map(|day| {
+                let stars = calendar.as_ref().map(|calendar| calendar.stars(day.day()));
+                DisplayPartialDay::new(day, stars)
+            })
             .collect::<Vec<_>>();
 
         let day = Select::new(message, days)
@@ -76,7 +91,7 @@ async fn year_loop(year: Arc<Year>, client: &AocClient, benchmark: bool) {
         };
 
         let alternatives = year.get_alternatives_for(day.day());
-        if let Some(alternatives) = alternatives {
+        if let Ok(Some(alternatives)) = alternatives {
             let alternatives = alternatives.iter()
                 .filter_map(|(&key, day)|
                     day.try_into_partial()
@@ -90,13 +105,13 @@ async fn year_loop(year: Arc<Year>, client: &AocClient, benchmark: bool) {
                 continue;
             }
 
-            if benchmark {
+            if let Some(options) = benchmark {
                 println!("Default implementation:");
-                bench_day(day, client).await;
+                bench_day(day, client, options).await;
 
                 for (key, day) in alternatives.into_iter().map(|day| (day.alternative(), day.day())) {
                     println!("{}:", key);
-                    bench_day(day, client).await;
+                    bench_day(day, client, options).await;
                 }
 
                 continue;
@@ -129,25 +144,129 @@ async fn year_loop(year: Arc<Year>, client: &AocClient, benchmark: bool) {
     }
 }
 
-async fn run_day(day: PartialDay, client: &AocClient, benchmark: bool) {
-    if benchmark {
-        bench_day(day, client).await;
+/// Load the stars of the year and point out where they don't match the code, the day list is
+/// shown without stars if the calendar is not available.
+async fn load_calendar(year: &Year, client: &AocClient) -> Option<Calendar> {
+    let calendar = match client.get_calendar(year.year()).await {
+        Ok(calendar) => calendar,
+        Err(err) => {
+            aoc_lib::io::print_debug(format!("The stars are not shown: {:#}", err));
+            return None;
+        }
+    };
+
+    let code_parts = |day: u8| match year.get_day(day) {
+        Ok(Day::Solved(_)) => 2,
+        Ok(Day::Partial(_) | Day::Final(_)) => 1,
+        Ok(Day::Unsolved(_)) | Err(_) => 0,
+    };
+    // The second star of the final day is awarded for the other days, not for code
+    let stars_with_code = |day: u8| if day == year.day_count() {
+        calendar.stars(day).min(1)
+    } else {
+        calendar.stars(day)
+    };
+
+    println!("{} of {} stars earned in {}", calendar.total(), 2 * year.day_count() as u32, year.year());
+    let missing_code = (1..=year.day_count())
+        .filter(|&day| stars_with_code(day) > code_parts(day))
+        .map(|day| format!("day {} ({} star(s), code for {} part(s))", day, calendar.stars(day), code_parts(day)))
+        .collect::<Vec<_>>();
+    if !missing_code.is_empty() {
+        aoc_lib::io::print_error(format!("Stars without code: {}", missing_code.join(", ")));
+    }
+    let missing_stars = (1..=year.day_count())
+        .filter(|&day| stars_with_code(day) < code_parts(day))
+        .map(|day| format!("day {} ({} star(s), code for {} part(s))", day, calendar.stars(day), code_parts(day)))
+        .collect::<Vec<_>>();
+    if !missing_stars.is_empty() {
+        aoc_lib::io::print_error(format!("Code without stars: {}", missing_stars.join(", ")));
+    }
+
+    Some(calendar)
+}
+
+async fn run_day(day: PartialDay, client: &AocClient, benchmark: Option<&BenchOptions>) {
+    if let Some(options) = benchmark {
+        bench_day(day, client, options).await;
     } else {
         day_prompt(day, client).await;
     }
 }
 
 async fn day_prompt(day: PartialDay, client: &AocClient) {
+    loop {
+        let action = Select::new("What do you want to do?", DayAction::ALL.to_vec())
+            .prompt();
+        let Ok(action) = handle_inquire_res(action) else {
+            return;
+        };
+
+        match action {
+            DayAction::Run => return run_prompt(day, client).await,
+            DayAction::RunExample => example_prompt(&day, client).await,
+            DayAction::ReadDescription => show_description(day.year(), day.day(), client).await,
+        }
+    }
+}
+
+async fn example_prompt(day: &PartialDay, client: &AocClient) {
+    let Some(examples) = handle_res(client.get_examples(day.year(), day.day()).await) else {
+        return;
+    };
+    if examples.is_empty() {
+        aoc_lib::io::print_error("No examples were found in the puzzle description");
+        return;
+    }
+
+    let examples = examples.into_iter()
+        .zip(1..)
+        .map(|(example, number)| DisplayExample::new(number, example))
+        .collect::<Vec<_>>();
+    let example = Select::new("Which example do you want to run?", examples)
+        .prompt()
+        .map(DisplayExample::example);
+    let Ok(example) = handle_inquire_res(example) else {
+        return;
+    };
+
+    let Some(outputs) = handle_res(day.run_parts(&TextInput::new(example.input())).await) else {
+        return;
+    };
+    for (part, res) in outputs {
+        println!("{}:", part);
+        let checked = res.and_then(|output| run::check_example(&example, part, &output).map(|check| (output, check)));
+        match checked {
+            Ok((output, LedgerCheck::Correct)) => {
+                println!("{}", output.message());
+                aoc_lib::io::print_success("The answer matches the example");
+            }
+            Ok((output, _)) => println!("{}", output.message()),
+            Err(err) => aoc_lib::io::print_error(format!("{:?}", err)),
+        }
+    }
+}
+
+async fn show_description(year: u16, day: u8, client: &AocClient) {
+    if let Some(description) = handle_res(client.get_puzzle_description(year, day).await) {
+        println!("{}", description.render(aoc_lib::io::text_width(), true));
+        println!();
+    }
+}
+
+async fn run_prompt(day: PartialDay, client: &AocClient) {
     let day = match day {
-        PartialDay::Partial(day) => {
+        PartialDay::Partial(day) | PartialDay::Final(day) => {
             let res = day.run_part1(client).await;
-            handle_res(res);
+            if let Some(output) = handle_res(res) {
+                submit::answer_prompt(day.year(), day.day(), Part::Part1, &output, client).await;
+            }
             return;
         }
         PartialDay::Solved(day) => day
     };
 
-    let part = Select::new("Which part do you want to run?", Part::vec())
+    let part = Select::new("Which part do you want to run?", Part::ALL.to_vec())
         .prompt();
     let Ok(part) = handle_inquire_res(part) else {
         return;
@@ -158,29 +277,27 @@ async fn day_prompt(day: PartialDay, client: &AocClient) {
         Part::Part2 => day.run_part2(client).await,
     };
 
-    handle_res(res);
+    if let Some(output) = handle_res(res) {
+        submit::answer_prompt(day.year(), day.day(), part, &output, client).await;
+    }
 }
 
-async fn bench_day(day: PartialDay, client: &AocClient) {
-    match day {
-        PartialDay::Partial(day) => {
-            let res = day.bench_part1(client, 50).await;
-            handle_res(res);
-        }
-        PartialDay::Solved(day) => {
-            let res = day.bench_part1(client, 50).await;
-            handle_res(res);
-            let res = day.bench_part2(client, 50).await;
-            handle_res(res);
-        }
+async fn bench_day(day: PartialDay, client: &AocClient, options: &BenchOptions) {
+    if let Some(parts) = handle_res(bench::bench_day(&day, client, options).await) {
+        bench::print_parts(&parts, options);
     }
 }
 
-fn handle_res<E>(res: Result<(), E>)
+/// Print the error if there is one, otherwise return the value.
+fn handle_res<T, E>(res: Result<T, E>) -> Option<T>
 where
     E: std::fmt::Debug + std::fmt::Display,
 {
-    if let Err(err) = res {
-        aoc_lib::io::print_error(format!("{:?}", err));
+    match res {
+        Ok(val) => Some(val),
+        Err(err) => {
+            aoc_lib::io::print_error(format!("{:?}", err));
+            None
+        }
     }
 }
@@ -1,6 +1,8 @@
 use std::sync::Arc;
 use aoc_lib::day::PartialDay;
+use aoc_lib::description::ExtractedExample;
 use aoc_lib::year::Year;
+use aoc_lib::Part;
 
 
 
@@ -26,11 +28,13 @@ impl<'a> std::fmt::Display for DisplayYear<'a> {
 
 pub struct DisplayPartialDay {
     day: PartialDay,
+    /// The stars earned on the day, if the calendar is known.
+    stars: Option<u8>,
 }
 
 impl DisplayPartialDay {
-    pub fn new(day: PartialDay) -> Self {
-        Self { day }
+    pub fn new(day: PartialDay, stars: Option<u8>) -> Self {
+        Self { day, stars }
     }
 
     pub fn day(self) -> PartialDay {
@@ -40,7 +44,20 @@ impl DisplayPartialDay {
 
 impl std::fmt::Display for DisplayPartialDay {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Day {}", self.day.day())
+        write!(f, "Day {:>2}", self.day.day())?;
+
+        if let Some(stars) = self.stars {
+            let code = self.day.parts().len() as u8;
+            write!(f, "  {:<2}", "*".repeat(stars as usize))?;
+            if code > stars {
+                write!(f, "  (code for {} part(s), but only {} star(s))", code, stars)?;
+            }
+        }
+        if matches!(self.day, PartialDay::Final(_)) {
+            write!(f, "  (final day, part 1 only)")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -69,22 +86,59 @@ impl std::fmt::Display for DisplayAlternatives {
     }
 }
 
-pub enum Part {
-    Part1,
-    Part2,
+/// What to do with the selected day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayAction {
+    Run,
+    RunExample,
+    ReadDescription,
 }
 
-impl Part {
-    pub fn vec() -> Vec<Self> {
-        vec![Self::Part1, Self::Part2]
-    }
+impl DayAction {
+    pub const ALL: [DayAction; 3] = [DayAction::Run, DayAction::RunExample, DayAction::ReadDescription];
 }
 
-impl std::fmt::Display for Part {
+impl std::fmt::Display for DayAction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Part1 => write!(f, "Part 1"),
-            Self::Part2 => write!(f, "Part 2"),
+            Self::Run => write!(f, "Run the solution"),
+            Self::RunExample => write!(f, "Run the solution on an example"),
+            Self::ReadDescription => write!(f, "Read the puzzle description"),
         }
     }
 }
+
+pub struct DisplayExample {
+    number: u8,
+    example: ExtractedExample,
+}
+
+impl DisplayExample {
+    pub fn new(number: u8, example: ExtractedExample) -> Self {
+        Self { number, example }
+    }
+
+    pub fn example(self) -> ExtractedExample {
+        self.example
+    }
+}
+
+impl std::fmt::Display for DisplayExample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let first_line = self.example.input().lines().next().unwrap_or_default();
+        let preview = match first_line.char_indices().nth(30) {
+            Some((end, _)) => format!("{}...", &first_line[..end]),
+            None => first_line.to_string(),
+        };
+        write!(f, "Example {}: {}", self.number, preview)?;
+
+        let expected = Part::ALL.into_iter()
+            .filter_map(|part| self.example.expected(part).map(|answer| format!("{}: {}", part, answer)))
+            .collect::<Vec<_>>();
+        if !expected.is_empty() {
+            write!(f, " ({})", expected.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
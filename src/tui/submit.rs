@@ -0,0 +1,65 @@
+use aoc_lib::aoc_client::SubmissionVerdict;
+use aoc_lib::ledger::LedgerCheck;
+use aoc_lib::puzzle::SolutionOutput;
+use aoc_lib::{io, AocClient, Part};
+use inquire::Confirm;
+
+use super::util::handle_inquire_res;
+
+/// Print the result of a run, compare it against the answer ledger and offer to submit it.
+pub async fn answer_prompt(year: u16, day: u8, part: Part, output: &SolutionOutput, client: &AocClient) {
+    println!("{}", output.message());
+
+    let answer = output.answer().to_string();
+    let mut ledger = match client.get_answer_ledger(year, day) {
+        Ok(ledger) => ledger,
+        Err(err) => {
+            io::print_error(format!("{:?}", err));
+            return;
+        }
+    };
+
+    // Don't bother the server with answers we already know the verdict for
+    match ledger.check_output(part, output) {
+        LedgerCheck::Unknown => {}
+        check @ LedgerCheck::Correct => {
+            io::print_success(check);
+            return;
+        }
+        check => {
+            io::print_error(check);
+            return;
+        }
+    }
+
+    if client.is_offline() {
+        io::print_debug("The answer can't be submitted in offline mode");
+        return;
+    }
+
+    let confirm = Confirm::new(&format!("Do you want to submit {} as the answer for {}?", answer, part))
+        .with_default(false)
+        .prompt();
+    let Ok(true) = handle_inquire_res(confirm) else {
+        return;
+    };
+
+    let verdict = match client.submit_answer(year, day, part, &answer).await {
+        Ok(verdict) => verdict,
+        Err(err) => {
+            io::print_error(format!("{:?}", err));
+            return;
+        }
+    };
+
+    match verdict {
+        SubmissionVerdict::Correct => io::print_success(verdict),
+        _ => io::print_error(verdict),
+    }
+
+    if ledger.record(part, &answer, verdict) {
+        if let Err(err) = client.save_answer_ledger(year, day, &ledger) {
+            io::print_error(format!("{:?}", err));
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use std::io::Write;
+use std::time::Duration;
+use anyhow::{anyhow, bail, Result};
+use aoc_lib::cache::Cache;
+use aoc_lib::schedule::{self, eastern};
+use aoc_lib::util::duration::DisplayCountdown;
+use aoc_lib::{io, AocClient};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::output::{csv_field, OutputFormat, Record, RecordWriter};
+
+/// How long to wait after the unlock before fetching, so a slightly fast clock doesn't ask the
+/// server too early.
+const GRACE: Duration = Duration::from_secs(2);
+
+/// Count down to the unlock of the puzzle, then fetch and cache its input and description.
+///
+/// Without a year the next puzzle to unlock is used, without a day the next day of the year.
+pub async fn wait(client: &AocClient, year: Option<u16>, day: Option<u8>, format: OutputFormat) -> Result<()> {
+    if client.is_offline() {
+        bail!("Waiting for a puzzle fetches it from the server, which isn't possible in offline mode");
+    }
+
+    let now = Utc::now();
+    let (year, day, unlock) = match (year, day) {
+        (Some(year), Some(day)) => {
+            let unlock = schedule::unlock_time(year, day)
+                .ok_or_else(|| anyhow!("There is no puzzle for day {} of {}", day, year))?;
+            (year, day, unlock)
+        }
+        (Some(year), None) => (1..=schedule::last_day(year))
+            .filter_map(|day| schedule::unlock_time(year, day).map(|unlock| (year, day, unlock)))
+            .find(|&(_, _, unlock)| unlock > now)
+            .ok_or_else(|| anyhow!("Every puzzle of {} is unlocked already", year))?,
+        (None, _) => schedule::next_unlock(now),
+    };
+
+    let human = format == OutputFormat::Human;
+    if human {
+        println!(
+            "Day {} of {} unlocks at {} US Eastern time",
+            day,
+            year,
+            unlock.with_timezone(&eastern()).format("%Y-%m-%d %H:%M:%S")
+        );
+    }
+    countdown(unlock, human).await;
+
+    let input = client.get_challenge(year, day).await?;
+    let puzzle = match client.fetch_puzzle_page(year, day).await {
+        Ok(_) => true,
+        Err(err) => {
+            if human {
+                io::print_error(format!("{:?}", err));
+            }
+            false
+        }
+    };
+
+    let record = WaitRecord {
+        year,
+        day,
+        input_path: client.cache().path(year, day, Cache::INPUT).display().to_string(),
+        input_lines: input.lines().count(),
+        puzzle_path: puzzle.then(|| client.cache().path(year, day, Cache::PUZZLE).display().to_string()),
+    };
+
+    match RecordWriter::new(format) {
+        Some(mut writer) => writer.write(&record),
+        None => io::print_success(format!(
+            "Fetched the input of day {} ({} lines), it is cached at {}",
+            day, record.input_lines, record.input_path
+        )),
+    }
+
+    Ok(())
+}
+
+/// Sleep until shortly after the unlock, showing the time left once a second.
+async fn countdown(unlock: DateTime<Utc>, show: bool) {
+    let target = unlock + GRACE;
+    while let Ok(left) = (target - Utc::now()).to_std() {
+        if left.is_zero() {
+            break;
+        }
+
+        if show {
+            print!("\r{} left ", DisplayCountdown(left));
+            let _ = std::io::stdout().flush();
+        }
+
+        // Wake up on the full second, so the countdown ticks evenly
+        let tick = Duration::from_nanos(left.subsec_nanos() as u64);
+        let tick = if tick.is_zero() { Duration::from_secs(1) } else { tick };
+        tokio::time::sleep(tick.min(left)).await;
+    }
+
+    if show {
+        println!();
+    }
+}
+
+/// The structured record of a fetched puzzle.
+#[derive(Serialize)]
+struct WaitRecord {
+    year: u16,
+    day: u8,
+    input_path: String,
+    input_lines: usize,
+    puzzle_path: Option<String>,
+}
+
+impl Record for WaitRecord {
+    const COLUMNS: &'static [&'static str] = &["year", "day", "input_path", "input_lines", "puzzle_path"];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.year.to_string(),
+            self.day.to_string(),
+            self.input_path.clone(),
+            self.input_lines.to_string(),
+            csv_field(&self.puzzle_path),
+        ]
+    }
+}
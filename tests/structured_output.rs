@@ -0,0 +1,69 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::process::Command;
+use std::thread;
+
+/// Serve canned responses to consecutive connections on a local port, returning the base url.
+fn serve(responses: Vec<(&'static str, &'static str)>) -> (String, thread::JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind local listener");
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = thread::spawn(move || {
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().expect("Failed to accept connection");
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            ).unwrap();
+        }
+    });
+
+    (base_url, handle)
+}
+
+#[test]
+fn test_retry_keeps_json_output_clean() {
+    let (base_url, server) = serve(vec![
+        ("500 Internal Server Error", "down"),
+        ("200 OK", "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n"),
+    ]);
+    let dir = std::env::temp_dir().join(format!("aoc-structured-output-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("config.toml"), "").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["--format", "json", "run", "2024", "1"])
+        .env("AOC_BASE_URL", &base_url)
+        .env("AOC_CACHE_DIR", dir.join("cache"))
+        .env("AOC_CONFIG", dir.join("config.toml"))
+        .env("AOC_SESSION_COOKIE", "ab".repeat(64))
+        .env_remove("AOC_ACCOUNT")
+        .env_remove("AOC_OFFLINE")
+        .output()
+        .expect("Failed to run aoc");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success(), "aoc failed: {}", stderr);
+    server.join().unwrap();
+    assert!(stderr.contains("retry 1 of"), "The retry is not reported: {}", stderr);
+
+    let records = stdout.lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap_or_else(|_| panic!("Not JSON: {:?}", line)))
+        .collect::<Vec<_>>();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0]["answer"], "11");
+}
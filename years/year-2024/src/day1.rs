@@ -1,8 +1,22 @@
 use aoc_lib::{SolutionPart1, SolutionPart2};
 use crate::prelude::*;
 
-create_solution!(1);
-
+create_solution!(1, examples = [
+    {
+        input: "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+",
+        part1: "11",
+        part2: "31",
+    },
+]);
+
+#[derive(Clone)]
 pub struct Input {
     pairs: Vec<(u32, u32)>,
 }
@@ -79,4 +93,8 @@ impl PuzzleInput for Input {
 
         Ok(Self { pairs })
     }
+
+    fn duplicate(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
\ No newline at end of file
@@ -4,7 +4,22 @@ use aoc_utils::matrix::ext::Yield;
 use aoc_utils::one_off::OneOff;
 use crate::prelude::*;
 
-create_solution!(10);
+create_solution!(10, examples = [
+    {
+        input: "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+",
+        part1: "36",
+        part2: "81",
+    },
+]);
 
 #[derive(Debug)]
 pub struct PuzzleInput {
@@ -3,9 +3,16 @@ use aoc_utils::itertools::{Either, Itertools};
 use aoc_utils::num::NumUtils;
 use aoc_utils::rustc_hash::FxHashMap;
 
-create_solution!(11);
-
-#[derive(Debug)]
+create_solution!(11, examples = [
+    {
+        input: "\
+125 17
+",
+        part1: "55312",
+    },
+]);
+
+#[derive(Debug, Clone)]
 pub struct PuzzleInput {
     stones: Vec<Stone>,
 }
@@ -92,6 +99,10 @@ impl aoc_lib::PuzzleInput for PuzzleInput {
 
         Ok(Self { stones })
     }
+
+    fn duplicate(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
 
 #[cfg(test)]
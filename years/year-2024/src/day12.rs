@@ -3,7 +3,24 @@ use aoc_utils::one_off::OneOff;
 use aoc_utils::rustc_hash::FxHashSet;
 use crate::prelude::*;
 
-create_solution!(12);
+create_solution!(12, examples = [
+    {
+        input: "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+",
+        part1: "1930",
+        part2: "1206",
+    },
+]);
 
 #[derive(Debug)]
 pub struct PuzzleInput {
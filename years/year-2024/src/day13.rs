@@ -4,11 +4,32 @@ use aoc_utils::itertools::Either;
 use aoc_utils::itertools::Itertools;
 use aoc_utils::nalgebra::{Matrix2, Vector2};
 
-create_solution!(13);
+create_solution!(13, examples = [
+    {
+        input: "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+",
+        part1: "480",
+    },
+]);
 create_alt_solution!(13, MultipleSolutions, "Handle Multiple Solutions");
 create_alt_solution!(13, NoMatrix, "No Matrix Equations");
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PuzzleInput {
     arcade_games: Vec<ArcadeGame>,
 }
@@ -162,9 +183,10 @@ impl ArcadeGame {
     }
 
     fn find_solution_no_matrix(&self) -> Option<(u64, u64)> {
-        let a = self.button_a.distance_moved;
-        let b = self.button_b.distance_moved;
-        let res = self.prize_location;
+        // The determinant can be negative, so calculate with signed integers.
+        let a = self.button_a.distance_moved.map(|x| x as i64);
+        let b = self.button_b.distance_moved.map(|x| x as i64);
+        let res = self.prize_location.map(|x| x as i64);
 
         let det = a.x * b.y - a.y * b.x;
         if det == 0 {
@@ -173,7 +195,11 @@ impl ArcadeGame {
 
         let res_a = (res.x * b.y - res.y * b.x) / det;
         let res_b = (a.x * res.y - a.y * res.x) / det;
+        if res_a < 0 || res_b < 0 {
+            return None;
+        }
 
+        let (res_a, res_b) = (res_a as u64, res_b as u64);
         if self.is_valid_move(res_a, res_b) {
             Some((res_a, res_b))
         } else {
@@ -332,6 +358,10 @@ impl aoc_lib::PuzzleInput for PuzzleInput {
 
         Ok(Self { arcade_games })
     }
+
+    fn duplicate(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
 
 #[cfg(test)]
@@ -389,4 +419,23 @@ mod tests {
         let actual = game.find_solution_alt();
         assert_eq!(expected, actual, "Got unexpected solution");
     }
+
+    #[test]
+    fn test_solution_no_matrix() {
+        // A negative determinant must not wrap around
+        let game = ArcadeGame {
+            button_a: Button { distance_moved: Vector2::new(22, 67) },
+            button_b: Button { distance_moved: Vector2::new(94, 34) },
+            prize_location: Vector2::new(8400, 5400),
+        };
+        assert_eq!(game.find_solution_no_matrix(), Some((40, 80)));
+
+        // The only solution needs negative moves
+        let game = ArcadeGame {
+            button_a: Button { distance_moved: Vector2::new(2, 1) },
+            button_b: Button { distance_moved: Vector2::new(1, 2) },
+            prize_location: Vector2::new(1, 5),
+        };
+        assert_eq!(game.find_solution_no_matrix(), None);
+    }
 }
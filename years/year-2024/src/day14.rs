@@ -5,7 +5,7 @@ use aoc_utils::nalgebra::Vector2;
 
 create_solution!(14);
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Input {
     robots: Vec<Robot>,
 }
@@ -120,6 +120,10 @@ impl PuzzleInput for Input {
 
         Ok(Input { robots: res })
     }
+
+    fn duplicate(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
 
 fn parse_coords<T>(input: &str) -> Result<Vector2<T>>
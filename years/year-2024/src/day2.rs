@@ -2,8 +2,22 @@ use std::ops::RangeInclusive;
 use aoc_lib::{SolutionPart1, SolutionPart2};
 use crate::prelude::*;
 
-create_solution!(2);
+create_solution!(2, examples = [
+    {
+        input: "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+",
+        part1: "2",
+        part2: "4",
+    },
+]);
 
+#[derive(Clone)]
 pub struct Input {
     reports: Vec<Report>,
 }
@@ -14,6 +28,7 @@ pub struct PuzzleResult {
 
 impl_puzzle_result!(PuzzleResult, "Number of valid reports {}", valid_reports);
 
+#[derive(Clone)]
 pub struct Report {
     levels: Vec<u32>,
 }
@@ -85,6 +100,10 @@ impl PuzzleInput for Input {
 
         Ok(Self { reports })
     }
+
+    fn duplicate(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
 
 impl Report {
@@ -3,8 +3,22 @@ use aoc_utils::lazy_regex::{lazy_regex, Lazy, Regex};
 use aoc_utils::lazy_regex;
 use crate::prelude::*;
 
-create_solution!(3);
-
+create_solution!(3, examples = [
+    {
+        input: "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+",
+        part1: "161",
+    },
+    {
+        input: "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+",
+        part2: "48",
+    },
+]);
+
+#[derive(Clone)]
 pub struct PuzzleInput {
     jumbled_instructions: String,
 }
@@ -72,11 +86,15 @@ impl SolutionPart2 for PuzzleSolution {
 }
 
 impl aoc_lib::PuzzleResult for PuzzleResult {
-    fn display(&self) -> () {
+    fn answer(&self) -> Answer {
+        self.result.into()
+    }
+
+    fn message(&self) -> String {
         if self.extended {
-            println!("Result of the extended jumbled instructions: {}", self.result)
+            format!("Result of the extended jumbled instructions: {}", self.result)
         } else {
-            println!("Result of the jumbled instructions: {}", self.result)
+            format!("Result of the jumbled instructions: {}", self.result)
         }
     }
 }
@@ -93,6 +111,10 @@ impl aoc_lib::PuzzleInput for PuzzleInput {
             jumbled_instructions: input,
         })
     }
+
+    fn duplicate(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
 
 impl Instruction {
@@ -2,7 +2,27 @@ use aoc_lib::{SolutionPart1, SolutionPart2};
 use aoc_utils::matrix::{Direction, Matrix};
 use crate::prelude::*;
 
-create_solution!(4);
+create_solution!(4, examples = [
+    {
+        input: "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+",
+        part1: "18",
+        part2: "9",
+    },
+]);
+
+create_puzzle_result!(PuzzleResultPart1, "Found {} xmases", xmases: usize);
+create_puzzle_result!(PuzzleResultPart2, "Found {} x-mas-es", x_mases: usize);
 
 pub struct PuzzleInput {
     word_search: Matrix<char>,
@@ -10,7 +30,7 @@ pub struct PuzzleInput {
 
 impl SolutionPart1 for PuzzleSolution {
     type Input = PuzzleInput;
-    type Result = String;
+    type Result = PuzzleResultPart1;
 
     /// Find all xmases in the word search
     fn solve(input: Self::Input) -> Result<Self::Result> {
@@ -31,13 +51,13 @@ impl SolutionPart1 for PuzzleSolution {
             })
             .sum::<usize>();
 
-        Ok(format!("Found {} xmases", result))
+        Ok(PuzzleResultPart1 { xmases: result })
     }
 }
 
 impl SolutionPart2 for PuzzleSolution {
     type Input = PuzzleInput;
-    type Result = String;
+    type Result = PuzzleResultPart2;
 
     /// Find all x-mas-es (aka two mas oriented in an x) in the word search
     fn solve(input: Self::Input) -> Result<Self::Result> {
@@ -68,7 +88,7 @@ impl SolutionPart2 for PuzzleSolution {
             })
             .count();
 
-        Ok(format!("Found {} x-mas-es", result))
+        Ok(PuzzleResultPart2 { x_mases: result })
     }
 }
 
@@ -1,7 +1,45 @@
 use aoc_lib::{SolutionPart1, SolutionPart2};
 use crate::prelude::*;
 
-create_solution!(5);
+create_solution!(5, examples = [
+    {
+        input: "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+",
+        part1: "143",
+        part2: "123",
+    },
+]);
+
+create_puzzle_result!(PuzzleResultPart1, "Found {} valid updates, result: {}", valid_updates: usize, result: u32);
+create_puzzle_result!(PuzzleResultPart2, "Found {} invalid updates\nResult of reordered invalid updates: {}", invalid_updates: usize, result: u32);
 
 #[derive(Debug)]
 pub struct PuzzleInput {
@@ -16,7 +54,7 @@ pub struct Update {
 
 impl SolutionPart1 for PuzzleSolution {
     type Input = PuzzleInput;
-    type Result = String;
+    type Result = PuzzleResultPart1;
 
     fn solve(input: Self::Input) -> Result<Self::Result> {
         let valid_updates = input.updates.iter().filter(|update| {
@@ -41,13 +79,13 @@ impl SolutionPart1 for PuzzleSolution {
         }).sum::<Option<u32>>()
             .ok_or_else(|| Anyhow::msg("Failed to sum, some valid updates are empty"))?;
 
-        Ok(format!("Found {} valid updates, result: {}", valid_updates.len(), res))
+        Ok(PuzzleResultPart1 { valid_updates: valid_updates.len(), result: res })
     }
 }
 
 impl SolutionPart2 for PuzzleSolution {
     type Input = PuzzleInput;
-    type Result = String;
+    type Result = PuzzleResultPart2;
 
     fn solve(input: Self::Input) -> Result<Self::Result> {
         let mut invalid_updates = input.updates.iter().filter(|update| {
@@ -95,7 +133,7 @@ impl SolutionPart2 for PuzzleSolution {
         }).sum::<Option<u32>>()
             .ok_or_else(|| Anyhow::msg("Failed to sum, some invalid updates are empty"))?;
 
-        Ok(format!("Found {} invalid updates\nResult of reordered invalid updates: {}", invalid_updates.len(), res))
+        Ok(PuzzleResultPart2 { invalid_updates: invalid_updates.len(), result: res })
     }
 }
 
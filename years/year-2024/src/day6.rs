@@ -2,7 +2,27 @@ use aoc_lib::{SolutionPart1, SolutionPart2};
 use crate::prelude::*;
 use aoc_utils::matrix::{Direction as MatrixDirection, Matrix};
 
-create_solution!(6);
+create_solution!(6, examples = [
+    {
+        input: "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+",
+        part1: "41",
+        part2: "6",
+    },
+]);
+
+create_puzzle_result!(PuzzleResultPart1, "Guard took {} steps and visited {} cells", steps: usize, visited: usize);
+create_puzzle_result!(PuzzleResultPart2, "Found {} cells where adding an obstacle would cause the guard to loop", loop_cells: usize);
 
 #[derive(Debug)]
 pub struct PuzzleInput {
@@ -43,7 +63,7 @@ enum GuardMoveError {
 
 impl SolutionPart1 for PuzzleSolution {
     type Input = PuzzleInput;
-    type Result = String;
+    type Result = PuzzleResultPart1;
 
     fn solve(input: Self::Input) -> Result<Self::Result> {
         let mut guard = input.initial_guard.clone();
@@ -76,13 +96,13 @@ impl SolutionPart1 for PuzzleSolution {
             .filter(|entry| entry.get().iter().any(|&visited| visited))
             .count();
 
-        Ok(format!("Guard took {} steps and visited {} cells", steps, visited))
+        Ok(PuzzleResultPart1 { steps, visited })
     }
 }
 
 impl SolutionPart2 for PuzzleSolution {
     type Input = PuzzleInput;
-    type Result = String;
+    type Result = PuzzleResultPart2;
 
     fn solve(input: Self::Input) -> Result<Self::Result> {
         let mut guard = input.initial_guard.clone();
@@ -144,7 +164,7 @@ impl SolutionPart2 for PuzzleSolution {
                 false
             }).count();
 
-        Ok(format!("Found {} cells where adding an obstacle would cause the guard to loop", result))
+        Ok(PuzzleResultPart2 { loop_cells: result })
     }
 }
 
@@ -1,14 +1,33 @@
 use aoc_lib::{SolutionPart1, SolutionPart2};
 use crate::prelude::*;
 
-create_solution!(7);
-
-#[derive(Debug)]
+create_solution!(7, examples = [
+    {
+        input: "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+",
+        part1: "3749",
+        part2: "11387",
+    },
+]);
+
+create_puzzle_result!(PuzzleResultPart1, "Found {} valid equations which total to {}", valid: u64, total: u64);
+create_puzzle_result!(PuzzleResultPart2, "Found {} extended valid equations which total to {}", valid: u64, total: u64);
+
+#[derive(Debug, Clone)]
 pub struct PuzzleInput {
     equations: Vec<Equation>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Equation {
     result: u64,
     operands: Vec<u64>,
@@ -16,7 +35,7 @@ pub struct Equation {
 
 impl SolutionPart1 for PuzzleSolution {
     type Input = PuzzleInput;
-    type Result = String;
+    type Result = PuzzleResultPart1;
 
     fn solve(input: Self::Input) -> Result<Self::Result> {
         let mut valid = 0u64;
@@ -25,13 +44,13 @@ impl SolutionPart1 for PuzzleSolution {
             .inspect(|_| valid += 1)
             .sum::<u64>();
 
-        Ok(format!("Found {} valid equations which total to {}", valid, result))
+        Ok(PuzzleResultPart1 { valid, total: result })
     }
 }
 
 impl SolutionPart2 for PuzzleSolution {
     type Input = PuzzleInput;
-    type Result = String;
+    type Result = PuzzleResultPart2;
 
     fn solve(input: Self::Input) -> Result<Self::Result> {
         let mut valid = 0u64;
@@ -40,7 +59,7 @@ impl SolutionPart2 for PuzzleSolution {
             .inspect(|_| valid += 1)
             .sum::<u64>();
 
-        Ok(format!("Found {} extended valid equations which total to {}", valid, result))
+        Ok(PuzzleResultPart2 { valid, total: result })
     }
 }
 
@@ -127,4 +146,8 @@ impl aoc_lib::PuzzleInput for PuzzleInput {
             .collect::<Result<Vec<Equation>, _>>()
             .map(|equations| PuzzleInput { equations })
     }
+
+    fn duplicate(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
\ No newline at end of file
@@ -3,7 +3,26 @@ use crate::prelude::*;
 use aoc_utils::matrix::{Matrix, MatrixEntry};
 use aoc_utils::rustc_hash::FxHashMap;
 
-create_solution!(8);
+create_solution!(8, examples = [
+    {
+        input: "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+",
+        part1: "14",
+        part2: "34",
+    },
+]);
 
 #[derive(Debug)]
 pub struct PuzzleInput {
@@ -112,11 +131,15 @@ impl SolutionPart2 for PuzzleSolution {
 }
 
 impl aoc_lib::PuzzleResult for PuzzleResult {
-    fn display(&self) {
+    fn answer(&self) -> Answer {
+        self.antinodes.into()
+    }
+
+    fn message(&self) -> String {
         if self.extended {
-            println!("Found {} extended antinodes", self.antinodes);
+            format!("Found {} extended antinodes", self.antinodes)
         } else {
-            println!("Found {} antinodes", self.antinodes);
+            format!("Found {} antinodes", self.antinodes)
         }
     }
 }
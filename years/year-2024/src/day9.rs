@@ -2,7 +2,15 @@ use crate::prelude::*;
 use std::iter::repeat_n;
 use aoc_utils::itertools::Itertools;
 
-create_solution!(9);
+create_solution!(9, examples = [
+    {
+        input: "\
+2333133121414131402
+",
+        part1: "1928",
+        part2: "2858",
+    },
+]);
 
 #[derive(Debug)]
 pub struct PuzzleInputPart1 {
@@ -201,11 +209,15 @@ impl PuzzleInput for PuzzleInputPart2 {
 }
 
 impl aoc_lib::PuzzleResult for PuzzleResult {
-    fn display(&self) {
+    fn answer(&self) -> Answer {
+        self.checksum.into()
+    }
+
+    fn message(&self) -> String {
         if self.alternative {
-            println!("Calculated checksum of alternative compressed file system: {}", self.checksum);
+            format!("Calculated checksum of alternative compressed file system: {}", self.checksum)
         } else {
-            println!("Calculated checksum of compressed file system: {}", self.checksum);
+            format!("Calculated checksum of compressed file system: {}", self.checksum)
         }
     }
 }
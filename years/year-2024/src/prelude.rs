@@ -1,7 +1,14 @@
 pub use aoc_lib::{
     PuzzleInput,
+    puzzle::Answer,
     impl_puzzle_result,
     create_puzzle_result,
+    create_solution,
+    create_alt_solution,
+    create_solution_part1,
+    create_alt_solution_part1,
+    create_solution_part2,
+    create_alt_solution_part2,
 };
 pub use aoc_utils::Anyhow;
 pub use aoc_utils::anyhow::{